@@ -0,0 +1,294 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use core::ops::Deref;
+
+use crate::{
+    Celsius, CloudCover, Fahrenheit, FogAndDewRisk, HeatIndexAlgorithm, RelativeHumidity,
+    Temperature, TemperatureAndRelativeHumidity, TemperatureAndWindSpeed, WindSpeed,
+};
+
+/// The "feels like" temperature.
+///
+/// The "feels like" temperature is the single value that best describes how the weather feels,
+/// selected following the rules of the US National Weather Service: the wind chill when it is
+/// cold and windy (temperature at or below 10°C (50°F) and wind speed of at least 4.8 km/h
+/// (3 mph)), the heat index when it is hot (temperature at or above 26.7°C (80°F)), and the air
+/// temperature otherwise.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FeelsLike<T: Temperature> {
+    temperature: T,
+    index: FeelsLikeIndex,
+}
+
+impl<T: Temperature> FeelsLike<T> {
+    /// Get the index that has been used to compute the "feels like" temperature.
+    pub fn index(&self) -> FeelsLikeIndex {
+        self.index
+    }
+}
+
+impl<T: Temperature> Deref for FeelsLike<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.temperature
+    }
+}
+
+/// The index used to compute a "feels like" temperature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeelsLikeIndex {
+    /// The air temperature, neither the wind chill nor the heat index apply.
+    #[default]
+    AirTemperature,
+    /// The wind chill, see [`WindChill`](crate::WindChill).
+    WindChill,
+    /// The heat index, see [`HeatIndex`](crate::HeatIndex).
+    HeatIndex,
+}
+
+/// The combination of the temperature, the relative humidity and the wind speed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TemperatureRelativeHumidityAndWindSpeed<T: Temperature> {
+    /// The temperature (either in °C or °F).
+    pub temperature: T,
+    /// The relative humidity (in %).
+    pub relative_humidity: RelativeHumidity,
+    /// The wind speed at 10 m above the ground (in m/s).
+    pub wind_speed: WindSpeed,
+}
+
+impl<T: Temperature> TemperatureRelativeHumidityAndWindSpeed<T> {
+    /// Computes the apparent temperature in the shade.
+    ///
+    /// The apparent temperature is the temperature felt by a person, taking into account the
+    /// effects of humidity and wind. It uses the formula of Steadman (1994) adopted by the
+    /// Australian Bureau of Meteorology, and is meaningful in all seasons.
+    pub fn apparent_temperature(&self) -> T {
+        T::from_celsius(Celsius(
+            self.temperature.celsius().value() + 0.33 * self.vapour_pressure()
+                - 0.7 * self.wind_speed.value()
+                - 4.,
+        ))
+    }
+
+    /// Computes the apparent temperature including the effect of the radiation.
+    ///
+    /// The `net_radiation` is the extra radiation absorbed per unit area of body surface (in
+    /// W/m²). See [`apparent_temperature()`](Self::apparent_temperature).
+    pub fn apparent_temperature_with_radiation(&self, net_radiation: f32) -> T {
+        T::from_celsius(Celsius(
+            self.temperature.celsius().value() + 0.348 * self.vapour_pressure()
+                - 0.7 * self.wind_speed.value()
+                + 0.7 * net_radiation / (self.wind_speed.value() + 10.)
+                - 4.25,
+        ))
+    }
+
+    /// Computes the "feels like" temperature, using the
+    /// [default heat index algorithm](HeatIndexAlgorithm::Simplified).
+    ///
+    /// See [`FeelsLike`].
+    pub fn feels_like(&self) -> FeelsLike<T> {
+        self.feels_like_with_algorithm(HeatIndexAlgorithm::default())
+    }
+
+    /// Computes the "feels like" temperature, using the given heat index algorithm when it is
+    /// hot.
+    ///
+    /// See [`FeelsLike`] and [`HeatIndexAlgorithm`].
+    pub fn feels_like_with_algorithm(&self, algorithm: HeatIndexAlgorithm) -> FeelsLike<T> {
+        if self.temperature.celsius().value() <= 10. && self.wind_speed.kilometers_per_hour() >= 4.8
+        {
+            let wind_chill = TemperatureAndWindSpeed {
+                temperature: self.temperature,
+                wind_speed: self.wind_speed,
+            }
+            .wind_chill();
+            FeelsLike {
+                temperature: *wind_chill,
+                index: FeelsLikeIndex::WindChill,
+            }
+        } else if self.temperature.fahrenheit().value() >= 80. {
+            let heat_index = TemperatureAndRelativeHumidity {
+                temperature: self.temperature,
+                relative_humidity: self.relative_humidity,
+            }
+            .heat_index_with_algorithm(algorithm);
+            FeelsLike {
+                temperature: *heat_index,
+                index: FeelsLikeIndex::HeatIndex,
+            }
+        } else {
+            FeelsLike {
+                temperature: self.temperature,
+                index: FeelsLikeIndex::AirTemperature,
+            }
+        }
+    }
+
+    /// Computes the fog and dew formation risk, from this observation, an observation made
+    /// `elapsed` seconds earlier and the cloud cover.
+    ///
+    /// Returns an error if the elapsed time is zero. See [`FogAndDewRisk`].
+    pub fn fog_and_dew_risk(
+        &self,
+        previous_observation: &TemperatureAndRelativeHumidity<T>,
+        elapsed: u64,
+        cloud_cover: CloudCover,
+    ) -> Result<FogAndDewRisk<T>, &'static str> {
+        FogAndDewRisk::new(self, previous_observation, elapsed, cloud_cover)
+    }
+
+    /// Computes the water vapour pressure (in hPa).
+    ///
+    /// The Bureau of Meteorology specifies this variant of the Magnus formula (6.105 hPa, 17.27,
+    /// 237.7°C) for the apparent temperature, so it is not shared with the other formulas.
+    fn vapour_pressure(&self) -> f32 {
+        let temperature = self.temperature.celsius().value();
+        self.relative_humidity.value() / 100.
+            * 6.105
+            * ((17.27 * temperature) / (237.7 + temperature)).exp()
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for TemperatureRelativeHumidityAndWindSpeed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.relative_humidity.eq(&other.relative_humidity)
+            && self.wind_speed.eq(&other.wind_speed)
+            && self.temperature.eq(&other.temperature)
+    }
+}
+
+impl From<TemperatureRelativeHumidityAndWindSpeed<Fahrenheit>>
+    for TemperatureRelativeHumidityAndWindSpeed<Celsius>
+{
+    fn from(value: TemperatureRelativeHumidityAndWindSpeed<Fahrenheit>) -> Self {
+        Self {
+            temperature: value.temperature.celsius(),
+            relative_humidity: value.relative_humidity,
+            wind_speed: value.wind_speed,
+        }
+    }
+}
+
+impl From<TemperatureRelativeHumidityAndWindSpeed<Celsius>>
+    for TemperatureRelativeHumidityAndWindSpeed<Fahrenheit>
+{
+    fn from(value: TemperatureRelativeHumidityAndWindSpeed<Celsius>) -> Self {
+        Self {
+            temperature: value.temperature.fahrenheit(),
+            relative_humidity: value.relative_humidity,
+            wind_speed: value.wind_speed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(30.), relative_humidity: RelativeHumidity::new(50.).unwrap(), wind_speed: WindSpeed(2.) }, 400., Celsius(31.58), Celsius(55.04))]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(5.), relative_humidity: RelativeHumidity::new(80.).unwrap(), wind_speed: WindSpeed(8.) }, 100., Celsius(-2.3), Celsius(1.46))]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(60.).unwrap(), wind_speed: WindSpeed(0.) }, 0., Celsius(20.62), Celsius(20.62))]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(86.), relative_humidity: RelativeHumidity::new(50.).unwrap(), wind_speed: WindSpeed(2.) }, 400., Fahrenheit(88.84), Fahrenheit(131.07))]
+    fn test_apparent_temperature_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndWindSpeed<T>,
+        #[case] net_radiation: f32,
+        #[case] expected_apparent_temperature: T,
+        #[case] expected_apparent_temperature_with_radiation: T,
+    ) {
+        assert_relative_eq!(
+            input.apparent_temperature().value(),
+            expected_apparent_temperature.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input
+                .apparent_temperature_with_radiation(net_radiation)
+                .value(),
+            expected_apparent_temperature_with_radiation.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(-10.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(20.) }, Celsius(-17.86), FeelsLikeIndex::WindChill)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(10.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(10.) }, Celsius(8.63), FeelsLikeIndex::WindChill)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(-10.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(3.) }, Celsius(-10.), FeelsLikeIndex::AirTemperature)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(18.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(30.) }, Celsius(18.), FeelsLikeIndex::AirTemperature)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(31.), relative_humidity: RelativeHumidity::new(60.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(30.) }, Celsius(34.84), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(14.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_miles_per_hour(12.) }, Fahrenheit(0.1), FeelsLikeIndex::WindChill)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(89.6), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed(0.) }, Fahrenheit(104.738), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(80.), relative_humidity: RelativeHumidity::new(40.).unwrap(), wind_speed: WindSpeed(0.) }, Fahrenheit(79.58), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(79.9), relative_humidity: RelativeHumidity::new(40.).unwrap(), wind_speed: WindSpeed(0.) }, Fahrenheit(79.9), FeelsLikeIndex::AirTemperature)]
+    fn test_feels_like_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndWindSpeed<T>,
+        #[case] expected_feels_like: T,
+        #[case] expected_index: FeelsLikeIndex,
+    ) {
+        let feels_like = input.feels_like();
+        assert_relative_eq!(
+            feels_like.value(),
+            expected_feels_like.value(),
+            epsilon = 0.01
+        );
+        assert_eq!(feels_like.index(), expected_index);
+    }
+
+    #[rstest]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(-10.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(20.) }, HeatIndexAlgorithm::Extended, Celsius(-17.86), FeelsLikeIndex::WindChill)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(18.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(30.) }, HeatIndexAlgorithm::Nws, Celsius(18.), FeelsLikeIndex::AirTemperature)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(110.), relative_humidity: RelativeHumidity::new(5.).unwrap(), wind_speed: WindSpeed::from_miles_per_hour(5.) }, HeatIndexAlgorithm::Simplified, Fahrenheit(99.52), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(110.), relative_humidity: RelativeHumidity::new(5.).unwrap(), wind_speed: WindSpeed::from_miles_per_hour(5.) }, HeatIndexAlgorithm::Nws, Fahrenheit(101.4), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(32.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed::from_kilometers_per_hour(10.) }, HeatIndexAlgorithm::Extended, Celsius(39.61), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(90.), relative_humidity: RelativeHumidity::new(70.).unwrap(), wind_speed: WindSpeed(0.) }, HeatIndexAlgorithm::Nws, Fahrenheit(105.92), FeelsLikeIndex::HeatIndex)]
+    fn test_feels_like_with_algorithm_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndWindSpeed<T>,
+        #[case] algorithm: HeatIndexAlgorithm,
+        #[case] expected_feels_like: T,
+        #[case] expected_index: FeelsLikeIndex,
+    ) {
+        let feels_like = input.feels_like_with_algorithm(algorithm);
+        assert_relative_eq!(
+            feels_like.value(),
+            expected_feels_like.value(),
+            epsilon = 0.01
+        );
+        assert_eq!(feels_like.index(), expected_index);
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(21.18), relative_humidity: RelativeHumidity::new(45.59).unwrap(), wind_speed: WindSpeed(3.5) },
+        TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(70.12), relative_humidity: RelativeHumidity::new(45.59).unwrap(), wind_speed: WindSpeed(3.5) }
+    )]
+    fn test_temperature_relative_humidity_and_wind_speed_celsius_to_fahrenheit_conversion(
+        #[case] input: TemperatureRelativeHumidityAndWindSpeed<Celsius>,
+        #[case] expected: TemperatureRelativeHumidityAndWindSpeed<Fahrenheit>,
+    ) {
+        let value: TemperatureRelativeHumidityAndWindSpeed<Fahrenheit> = input.into();
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(70.12), relative_humidity: RelativeHumidity::new(45.59).unwrap(), wind_speed: WindSpeed(3.5) },
+        TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(21.18), relative_humidity: RelativeHumidity::new(45.59).unwrap(), wind_speed: WindSpeed(3.5) }
+    )]
+    fn test_temperature_relative_humidity_and_wind_speed_fahrenheit_to_celsius_conversion(
+        #[case] input: TemperatureRelativeHumidityAndWindSpeed<Fahrenheit>,
+        #[case] expected: TemperatureRelativeHumidityAndWindSpeed<Celsius>,
+    ) {
+        let value: TemperatureRelativeHumidityAndWindSpeed<Celsius> = input.into();
+        assert_eq!(value, expected);
+    }
+}
@@ -0,0 +1,925 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use crate::contrail::{
+    saturation_vapour_pressure_over_ice, saturation_vapour_pressure_over_water,
+    DEFAULT_PROPULSION_EFFICIENCY, MINIMUM_CONTRAIL_PRESSURE,
+};
+use crate::humidity;
+use crate::{
+    Altitude, BarometricPressure, Celsius, ContrailFormation, Fahrenheit, PrecipitableWater,
+    PrecipitationTypeProbabilities, RelativeHumidity, Temperature,
+    TemperatureAndBarometricPressure, TemperatureAndRelativeHumidity,
+};
+
+/// The offset between the Celsius and the Kelvin scales.
+pub(crate) const KELVIN: f32 = 273.15;
+/// The standard gravity (in m/s²).
+const GRAVITY: f32 = 9.81;
+/// The dry adiabatic lapse rate (in K/m).
+const DRY_ADIABATIC_LAPSE_RATE: f32 = 0.0098;
+/// The ratio between the specific gas constant and the specific heat capacity of dry air.
+pub(crate) const KAPPA: f32 = 0.2857;
+/// The ratio between the specific gas constants of dry air and water vapour.
+pub(crate) const EPSILON: f32 = 0.622;
+/// The reference pressure of the potential temperatures (in hPa).
+const REFERENCE_PRESSURE: f32 = 1_000.;
+/// The specific gas constant of dry air (in J/(kg·K)).
+pub(crate) const GAS_CONSTANT_AIR: f32 = 287.04;
+/// The specific heat capacity of dry air at constant pressure (in J/(kg·K)).
+const SPECIFIC_HEAT_AIR: f32 = 1_005.7;
+/// The latent heat of vaporisation of water (in J/kg).
+const LATENT_HEAT_VAPORISATION: f32 = 2.501e6;
+/// The typical depth below the wet-bulb zero height down to which snow falls (in m).
+pub(crate) const SNOW_LEVEL_DEPTH: f32 = 300.;
+/// The largest pressure step of the pseudo-adiabat integration (in hPa).
+const PSEUDO_ADIABAT_STEP: f32 = 10.;
+
+/// The lifting condensation level.
+///
+/// The lifting condensation level (LCL) is the level at which an air parcel lifted dry
+/// adiabatically from the surface becomes saturated. It is a good estimate of the height of the
+/// base of cumulus clouds.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LiftingCondensationLevel<T: Temperature> {
+    height: Altitude,
+    temperature: T,
+    barometric_pressure: BarometricPressure,
+}
+
+impl<T: Temperature> LiftingCondensationLevel<T> {
+    /// Get the height of the lifting condensation level above the ground (in m).
+    pub fn height(&self) -> Altitude {
+        self.height
+    }
+
+    /// Get the temperature at the lifting condensation level.
+    pub fn temperature(&self) -> T {
+        self.temperature
+    }
+
+    /// Get the barometric pressure at the lifting condensation level (in hPa).
+    pub fn barometric_pressure(&self) -> BarometricPressure {
+        self.barometric_pressure
+    }
+}
+
+/// The algorithm used to compute the lifting condensation level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LiftingCondensationLevelAlgorithm {
+    /// The rule of Espy: the height of the lifting condensation level is 125 m per degree of
+    /// spread between the temperature and the dew point. The temperature and the pressure at
+    /// the lifting condensation level follow the dry adiabat.
+    Espy,
+    /// The exact analytical formula of Romps (2017), based on the Lambert W function.
+    #[default]
+    Romps,
+}
+
+/// The combination of the temperature, the relative humidity and the barometric pressure.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TemperatureRelativeHumidityAndBarometricPressure<T: Temperature> {
+    /// The temperature (either in °C or °F).
+    pub temperature: T,
+    /// The relative humidity (in %).
+    pub relative_humidity: RelativeHumidity,
+    /// The barometric pressure (in hPa).
+    pub barometric_pressure: BarometricPressure,
+}
+
+impl<T: Temperature> TemperatureRelativeHumidityAndBarometricPressure<T> {
+    /// Create a TemperatureRelativeHumidityAndBarometricPressure from the relative humidity
+    /// with respect to ice (in %), as measured in the upper troposphere.
+    ///
+    /// Returns an error if the air would be supersaturated with respect to water.
+    pub fn from_relative_humidity_over_ice(
+        temperature: T,
+        relative_humidity_over_ice: f32,
+        barometric_pressure: BarometricPressure,
+    ) -> Result<Self, &'static str> {
+        let celsius = temperature.celsius().value();
+        Ok(Self {
+            temperature,
+            relative_humidity: RelativeHumidity::new(
+                relative_humidity_over_ice * saturation_vapour_pressure_over_ice(celsius)
+                    / saturation_vapour_pressure_over_water(celsius),
+            )?,
+            barometric_pressure,
+        })
+    }
+
+    /// Create a TemperatureRelativeHumidityAndBarometricPressure from the dew point, e.g. to
+    /// compute the lifting condensation level from a surface observation.
+    ///
+    /// The relative humidity is computed with the Magnus formula used by
+    /// [`TemperatureAndRelativeHumidity::dew_point()`].
+    ///
+    /// Returns an error if the dew point is above the temperature.
+    pub fn from_dew_point(
+        temperature: T,
+        dew_point: T,
+        barometric_pressure: BarometricPressure,
+    ) -> Result<Self, &'static str> {
+        let celsius = temperature.celsius().value();
+        let dew_point = dew_point.celsius().value();
+        if dew_point > celsius {
+            return Err("Dew point must not be above temperature");
+        }
+        Ok(Self {
+            temperature,
+            relative_humidity: RelativeHumidity::new(
+                100. * humidity::saturation_vapour_pressure(dew_point)
+                    / humidity::saturation_vapour_pressure(celsius),
+            )?,
+            barometric_pressure,
+        })
+    }
+
+    /// Computes the lifting condensation level, using the
+    /// [default algorithm](LiftingCondensationLevelAlgorithm::Romps).
+    ///
+    /// Returns an error if the relative humidity is 0 %, as the air never becomes saturated.
+    /// See [`LiftingCondensationLevel`].
+    pub fn lifting_condensation_level(&self) -> Result<LiftingCondensationLevel<T>, &'static str> {
+        self.lifting_condensation_level_with_algorithm(LiftingCondensationLevelAlgorithm::default())
+    }
+
+    /// Computes the lifting condensation level, using the given algorithm.
+    ///
+    /// Returns an error if the relative humidity is 0 %, as the air never becomes saturated.
+    /// See [`LiftingCondensationLevel`] and [`LiftingCondensationLevelAlgorithm`].
+    pub fn lifting_condensation_level_with_algorithm(
+        &self,
+        algorithm: LiftingCondensationLevelAlgorithm,
+    ) -> Result<LiftingCondensationLevel<T>, &'static str> {
+        if self.relative_humidity.value() <= 0. {
+            return Err("Relative humidity must be above 0 %");
+        }
+        let (height, temperature, barometric_pressure) = match algorithm {
+            LiftingCondensationLevelAlgorithm::Espy => self.espy_lifting_condensation_level(),
+            LiftingCondensationLevelAlgorithm::Romps => romps::lifting_condensation_level(
+                self.temperature.celsius().value() + KELVIN,
+                self.relative_humidity.value() / 100.,
+                self.barometric_pressure.value() * 100.,
+            ),
+        };
+        Ok(LiftingCondensationLevel {
+            height: Altitude(height),
+            temperature: T::from_celsius(Celsius(temperature - KELVIN)),
+            barometric_pressure: BarometricPressure(barometric_pressure / 100.),
+        })
+    }
+
+    /// Computes the total precipitable water (in mm) of a vertical profile, ordered from the
+    /// ground to the top.
+    ///
+    /// The precipitable water is the depth of the water that would be obtained if all the water
+    /// vapour of the column condensed. The specific humidity is integrated over the pressure with
+    /// the trapezoidal rule, so the profile should reach the upper troposphere (300 hPa or above)
+    /// to account for most of the water vapour.
+    ///
+    /// Returns an error if there are less than two levels or if the pressure does not strictly
+    /// decrease with the levels.
+    pub fn precipitable_water(profile: &[Self]) -> Result<PrecipitableWater, &'static str> {
+        if profile.len() < 2 {
+            return Err("A profile needs at least two levels");
+        }
+        if profile
+            .windows(2)
+            .any(|pair| pair[1].barometric_pressure.value() >= pair[0].barometric_pressure.value())
+        {
+            return Err("Pressure must strictly decrease with the levels");
+        }
+        Ok(precipitable_water(profile.iter().map(|level| {
+            (
+                level.barometric_pressure.value(),
+                level.relative_humidity.value() / 100.
+                    * saturation_vapour_pressure(level.temperature.celsius().value()),
+            )
+        })))
+    }
+
+    /// Computes the virtual temperature.
+    ///
+    /// The virtual temperature is the temperature at which dry air would have the same density
+    /// as the moist air, at the same pressure.
+    pub fn virtual_temperature(&self) -> T {
+        T::from_celsius(Celsius(self.virtual_temperature_kelvin() - KELVIN))
+    }
+
+    /// Computes the virtual potential temperature.
+    ///
+    /// The virtual potential temperature is the potential temperature of the virtual
+    /// temperature, used to assess the static stability of moist air.
+    pub fn virtual_potential_temperature(&self) -> T {
+        T::from_celsius(Celsius(
+            potential_temperature(
+                self.virtual_temperature_kelvin(),
+                self.barometric_pressure.value(),
+            ) - KELVIN,
+        ))
+    }
+
+    /// Computes the equivalent potential temperature, using the formula of Bolton (1980).
+    ///
+    /// The equivalent potential temperature is the temperature an air parcel would reach if all
+    /// its water vapour condensed, releasing its latent heat, and it was brought adiabatically to
+    /// the reference pressure of 1000 hPa. It is conserved during both dry and moist adiabatic
+    /// processes.
+    pub fn equivalent_potential_temperature(&self) -> T {
+        T::from_celsius(Celsius(
+            equivalent_potential_temperature(
+                self.temperature.celsius().value() + KELVIN,
+                self.relative_humidity.value(),
+                self.barometric_pressure.value(),
+            ) - KELVIN,
+        ))
+    }
+
+    /// Estimates the wet-bulb zero height, the altitude at which the wet-bulb temperature
+    /// reaches 0°C.
+    ///
+    /// The altitude of the station is computed from the barometric pressure, and the wet-bulb
+    /// temperature is assumed to decrease with the saturated adiabatic lapse rate above the
+    /// station. When the wet-bulb temperature is at or below 0°C, the wet-bulb zero height is
+    /// the altitude of the station.
+    pub fn wet_bulb_zero_height(&self) -> Altitude {
+        let barometric_pressure = self.barometric_pressure.value();
+        let wet_bulb = self.wet_bulb().max(0.);
+        let altitude = TemperatureAndBarometricPressure {
+            temperature: self.temperature,
+            barometric_pressure: self.barometric_pressure,
+        }
+        .altitude();
+        // The lapse rate is taken halfway between the station and the wet-bulb zero height.
+        Altitude(
+            altitude.value()
+                + wet_bulb
+                    / moist_adiabatic_lapse_rate(wet_bulb / 2. + KELVIN, barometric_pressure),
+        )
+    }
+
+    /// Estimates the snow level, the altitude down to which precipitation falls as snow.
+    ///
+    /// Melting snow cools the air down, so snow usually reaches about 300 m below the
+    /// [wet-bulb zero height](Self::wet_bulb_zero_height).
+    pub fn snow_level(&self) -> Altitude {
+        Altitude(self.wet_bulb_zero_height().value() - SNOW_LEVEL_DEPTH)
+    }
+
+    /// Applies the Schmidt-Appleman criterion to the contrails of aircraft flying at this
+    /// pressure level, with the propulsion efficiency of modern jet engines (0.3).
+    ///
+    /// Returns an error if the barometric pressure is below 20 hPa. See [`ContrailFormation`].
+    pub fn contrail_formation(&self) -> Result<ContrailFormation<T>, &'static str> {
+        self.contrail_formation_with_propulsion_efficiency(DEFAULT_PROPULSION_EFFICIENCY)
+    }
+
+    /// Applies the Schmidt-Appleman criterion to the contrails of aircraft flying at this
+    /// pressure level, with the given overall propulsion efficiency of the engines.
+    ///
+    /// More efficient engines release less heat with the same amount of water vapour, so
+    /// they form contrails at higher temperatures. Returns an error if the propulsion
+    /// efficiency is not between 0 (included) and 1 (excluded), or if the barometric pressure
+    /// is below 20 hPa, where the approximation of Schumann (1996) diverges. See
+    /// [`ContrailFormation`].
+    pub fn contrail_formation_with_propulsion_efficiency(
+        &self,
+        propulsion_efficiency: f32,
+    ) -> Result<ContrailFormation<T>, &'static str> {
+        if !(0.0..1.0).contains(&propulsion_efficiency) {
+            return Err("Propulsion efficiency must be between 0 and 1");
+        }
+        if self.barometric_pressure.value() < MINIMUM_CONTRAIL_PRESSURE {
+            return Err("Barometric pressure must be at least 20 hPa");
+        }
+        Ok(ContrailFormation::new(
+            self.temperature,
+            self.relative_humidity.value(),
+            self.barometric_pressure.value(),
+            propulsion_efficiency,
+        ))
+    }
+
+    /// Computes the probabilities of the types of precipitation at the surface.
+    ///
+    /// See [`PrecipitationTypeProbabilities`].
+    pub fn precipitation_type(&self) -> PrecipitationTypeProbabilities {
+        PrecipitationTypeProbabilities::new(
+            self.temperature.celsius().value(),
+            self.wet_bulb(),
+            self.relative_humidity.value(),
+        )
+    }
+
+    /// Computes the thermodynamic wet-bulb temperature (in °C).
+    fn wet_bulb(&self) -> f32 {
+        TemperatureAndRelativeHumidity {
+            temperature: self.temperature,
+            relative_humidity: self.relative_humidity,
+        }
+        .psychrometric_state(self.barometric_pressure)
+        .wet_bulb()
+        .celsius()
+        .value()
+    }
+
+    /// Computes the lifting condensation level with the rule of Espy, returning its height
+    /// (in m), its temperature (in K) and its pressure (in Pa).
+    fn espy_lifting_condensation_level(&self) -> (f32, f32, f32) {
+        let temperature = self.temperature.celsius().value();
+        let dew_point = TemperatureAndRelativeHumidity {
+            temperature: self.temperature,
+            relative_humidity: self.relative_humidity,
+        }
+        .dew_point()
+        .celsius()
+        .value();
+        let height = 125. * (temperature - dew_point);
+        let lcl_temperature = temperature + KELVIN - DRY_ADIABATIC_LAPSE_RATE * height;
+        (
+            height,
+            lcl_temperature,
+            self.barometric_pressure.value()
+                * 100.
+                * (lcl_temperature / (temperature + KELVIN)).powf(1. / KAPPA),
+        )
+    }
+
+    /// Computes the virtual temperature (in K).
+    fn virtual_temperature_kelvin(&self) -> f32 {
+        let temperature = self.temperature.celsius().value();
+        let vapour_pressure =
+            self.relative_humidity.value() / 100. * saturation_vapour_pressure(temperature);
+        (temperature + KELVIN)
+            / (1. - vapour_pressure / self.barometric_pressure.value() * (1. - EPSILON))
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for TemperatureRelativeHumidityAndBarometricPressure<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.relative_humidity.eq(&other.relative_humidity)
+            && self.barometric_pressure.eq(&other.barometric_pressure)
+            && self.temperature.eq(&other.temperature)
+    }
+}
+
+impl From<TemperatureRelativeHumidityAndBarometricPressure<Fahrenheit>>
+    for TemperatureRelativeHumidityAndBarometricPressure<Celsius>
+{
+    fn from(value: TemperatureRelativeHumidityAndBarometricPressure<Fahrenheit>) -> Self {
+        Self {
+            temperature: value.temperature.celsius(),
+            relative_humidity: value.relative_humidity,
+            barometric_pressure: value.barometric_pressure,
+        }
+    }
+}
+
+impl From<TemperatureRelativeHumidityAndBarometricPressure<Celsius>>
+    for TemperatureRelativeHumidityAndBarometricPressure<Fahrenheit>
+{
+    fn from(value: TemperatureRelativeHumidityAndBarometricPressure<Celsius>) -> Self {
+        Self {
+            temperature: value.temperature.fahrenheit(),
+            relative_humidity: value.relative_humidity,
+            barometric_pressure: value.barometric_pressure,
+        }
+    }
+}
+
+/// Computes the saturation vapour pressure (in hPa) at the given temperature (in °C), using the
+/// formula of Bolton (1980), which his formulas of the equivalent potential temperature and of the
+/// temperature at the lifting condensation level are fitted to.
+pub(crate) fn saturation_vapour_pressure(temperature: f32) -> f32 {
+    6.112 * (17.67 * temperature / (temperature + 243.5)).exp()
+}
+
+/// Computes the dew point (in °C) corresponding to the given vapour pressure (in hPa), inverting
+/// the formula of Bolton (1980).
+pub(crate) fn dew_point(vapour_pressure: f32) -> f32 {
+    let ln = (vapour_pressure / 6.112).ln();
+    243.5 * ln / (17.67 - ln)
+}
+
+/// Computes the mixing ratio (in kg/kg) from the dew point (in °C) and the pressure (in hPa).
+pub(crate) fn mixing_ratio(dew_point: f32, pressure: f32) -> f32 {
+    let vapour_pressure = saturation_vapour_pressure(dew_point);
+    EPSILON * vapour_pressure / (pressure - vapour_pressure)
+}
+
+/// Computes the virtual temperature (in K) from the temperature (in K) and the mixing ratio
+/// (in kg/kg).
+pub(crate) fn virtual_temperature(temperature: f32, mixing_ratio: f32) -> f32 {
+    temperature * (1. + mixing_ratio / EPSILON) / (1. + mixing_ratio)
+}
+
+/// Computes the potential temperature (in K) from the temperature (in K) and the pressure
+/// (in hPa).
+pub(crate) fn potential_temperature(temperature: f32, pressure: f32) -> f32 {
+    temperature * (REFERENCE_PRESSURE / pressure).powf(KAPPA)
+}
+
+/// Computes the equivalent potential temperature (in K) from the temperature (in K), the
+/// relative humidity (in %) and the pressure (in hPa), using the formula (43) of Bolton (1980).
+pub(crate) fn equivalent_potential_temperature(
+    temperature: f32,
+    relative_humidity: f32,
+    pressure: f32,
+) -> f32 {
+    let vapour_pressure =
+        relative_humidity / 100. * saturation_vapour_pressure(temperature - KELVIN);
+    // Mixing ratio (in g/kg).
+    let mixing_ratio = 1_000. * EPSILON * vapour_pressure / (pressure - vapour_pressure);
+    // Temperature at the lifting condensation level (in K), formula (22) of Bolton (1980).
+    let lcl_temperature =
+        1. / (1. / (temperature - 55.) - (relative_humidity / 100.).ln() / 2_840.) + 55.;
+    temperature
+        * (REFERENCE_PRESSURE / pressure).powf(0.2854 * (1. - 0.28e-3 * mixing_ratio))
+        * ((3.376 / lcl_temperature - 0.002_54) * mixing_ratio * (1. + 0.81e-3 * mixing_ratio))
+            .exp()
+}
+
+/// Computes the precipitable water (in mm) of a column given by its levels (pressure and vapour
+/// pressure, both in hPa), ordered from the ground to the top.
+pub(crate) fn precipitable_water(levels: impl Iterator<Item = (f32, f32)>) -> f32 {
+    let mut previous: Option<(f32, f32)> = None;
+    let mut integral = 0.;
+    for (pressure, vapour_pressure) in levels {
+        let specific_humidity =
+            EPSILON * vapour_pressure / (pressure - (1. - EPSILON) * vapour_pressure);
+        if let Some((previous_pressure, previous_specific_humidity)) = previous {
+            integral += (previous_specific_humidity + specific_humidity) / 2.
+                * (previous_pressure - pressure);
+        }
+        previous = Some((pressure, specific_humidity));
+    }
+    // The pressures are in hPa, and 1 kg/m² of water is 1 mm deep.
+    100. * integral / GRAVITY
+}
+
+/// Computes the saturated (pseudo-)adiabatic lapse rate (in K/m) at the given temperature (in K)
+/// and pressure (in hPa).
+pub(crate) fn moist_adiabatic_lapse_rate(temperature: f32, pressure: f32) -> f32 {
+    let mixing_ratio = mixing_ratio(temperature - KELVIN, pressure);
+    GRAVITY * (1. + LATENT_HEAT_VAPORISATION * mixing_ratio / (GAS_CONSTANT_AIR * temperature))
+        / (SPECIFIC_HEAT_AIR
+            + LATENT_HEAT_VAPORISATION.powi(2) * mixing_ratio * EPSILON
+                / (GAS_CONSTANT_AIR * temperature.powi(2)))
+}
+
+/// Computes the temperature (in K) of a saturated parcel brought pseudo-adiabatically from the
+/// given temperature (in K) and pressure (in hPa) to the target pressure (in hPa).
+///
+/// The pseudo-adiabat dT/dp is integrated with the fourth order Runge-Kutta method.
+pub(crate) fn pseudo_adiabat(temperature: f32, pressure: f32, target_pressure: f32) -> f32 {
+    // Hydrostatic balance turns the lapse rate (in K/m) into a gradient with the pressure.
+    let gradient = |temperature: f32, pressure: f32| {
+        moist_adiabatic_lapse_rate(temperature, pressure) * GAS_CONSTANT_AIR * temperature
+            / (GRAVITY * pressure)
+    };
+    let steps = ((target_pressure - pressure).abs() / PSEUDO_ADIABAT_STEP)
+        .ceil()
+        .max(1.);
+    let step = (target_pressure - pressure) / steps;
+    let mut temperature = temperature;
+    for index in 0..steps as usize {
+        let pressure = pressure + index as f32 * step;
+        let k1 = gradient(temperature, pressure);
+        let k2 = gradient(temperature + step / 2. * k1, pressure + step / 2.);
+        let k3 = gradient(temperature + step / 2. * k2, pressure + step / 2.);
+        let k4 = gradient(temperature + step * k3, pressure + step);
+        temperature += step / 6. * (k1 + 2. * k2 + 2. * k3 + k4);
+    }
+    temperature
+}
+
+/// The lifting condensation level of Romps (2017), "Exact expression for the lifting
+/// condensation level", Journal of the Atmospheric Sciences.
+mod romps {
+    #[allow(unused_imports)]
+    #[cfg(feature = "no-std")]
+    use micromath::F32Ext;
+
+    use super::GRAVITY;
+
+    const TRIPLE_POINT_TEMPERATURE: f32 = 273.16;
+    const LATENT_HEAT: f32 = 2.374e6;
+    const GAS_CONSTANT_AIR: f32 = 287.04;
+    const GAS_CONSTANT_VAPOUR: f32 = 461.;
+    const HEAT_CAPACITY_AIR: f32 = 719. + GAS_CONSTANT_AIR;
+    const HEAT_CAPACITY_VAPOUR: f32 = 1_418. + GAS_CONSTANT_VAPOUR;
+    const HEAT_CAPACITY_VAPOUR_VOLUME: f32 = 1_418.;
+    const HEAT_CAPACITY_LIQUID: f32 = 4_119.;
+    const TRIPLE_POINT_PRESSURE: f32 = 611.65;
+
+    /// Computes the lifting condensation level from the temperature (in K), the relative
+    /// humidity (between 0 and 1) and the pressure (in Pa), returning its height (in m), its
+    /// temperature (in K) and its pressure (in Pa).
+    pub(super) fn lifting_condensation_level(
+        temperature: f32,
+        relative_humidity: f32,
+        pressure: f32,
+    ) -> (f32, f32, f32) {
+        let vapour_pressure = relative_humidity * saturation_vapour_pressure(temperature);
+        let specific_humidity = GAS_CONSTANT_AIR * vapour_pressure
+            / (GAS_CONSTANT_VAPOUR * pressure
+                + (GAS_CONSTANT_AIR - GAS_CONSTANT_VAPOUR) * vapour_pressure);
+        let gas_constant =
+            (1. - specific_humidity) * GAS_CONSTANT_AIR + specific_humidity * GAS_CONSTANT_VAPOUR;
+        let heat_capacity =
+            (1. - specific_humidity) * HEAT_CAPACITY_AIR + specific_humidity * HEAT_CAPACITY_VAPOUR;
+        let a = -(HEAT_CAPACITY_VAPOUR - HEAT_CAPACITY_LIQUID) / GAS_CONSTANT_VAPOUR
+            + heat_capacity / gas_constant;
+        let b = -(LATENT_HEAT
+            - (HEAT_CAPACITY_VAPOUR_VOLUME - HEAT_CAPACITY_LIQUID) * TRIPLE_POINT_TEMPERATURE)
+            / (GAS_CONSTANT_VAPOUR * temperature);
+        let c = relative_humidity * b.exp();
+        let lcl_temperature =
+            b / (a * lambert_w_lower_branch(b / a * c.powf(1. / a))) * temperature;
+        (
+            heat_capacity * (temperature - lcl_temperature) / GRAVITY,
+            lcl_temperature,
+            pressure * (lcl_temperature / temperature).powf(heat_capacity / gas_constant),
+        )
+    }
+
+    /// Computes the saturation vapour pressure over liquid water (in Pa) at the given
+    /// temperature (in K), which the exact solution of Romps (2017) is derived from.
+    fn saturation_vapour_pressure(temperature: f32) -> f32 {
+        TRIPLE_POINT_PRESSURE
+            * (temperature / TRIPLE_POINT_TEMPERATURE)
+                .powf((HEAT_CAPACITY_VAPOUR - HEAT_CAPACITY_LIQUID) / GAS_CONSTANT_VAPOUR)
+            * ((LATENT_HEAT
+                - (HEAT_CAPACITY_VAPOUR_VOLUME - HEAT_CAPACITY_LIQUID) * TRIPLE_POINT_TEMPERATURE)
+                / GAS_CONSTANT_VAPOUR
+                * (1. / TRIPLE_POINT_TEMPERATURE - 1. / temperature))
+                .exp()
+    }
+
+    /// Computes the lower branch (W₋₁) of the Lambert W function, defined for x between -1/e
+    /// and 0, using Halley's method.
+    fn lambert_w_lower_branch(x: f32) -> f32 {
+        let mut w = if x < -0.25 {
+            let p = -(2. * (1. + core::f32::consts::E * x)).max(0.).sqrt();
+            -1. + p - p * p / 3. + 11. / 72. * p * p * p
+        } else {
+            let l = (-x).ln();
+            l - (-l).ln()
+        };
+        for _ in 0..10 {
+            let e = w.exp();
+            let f = w * e - x;
+            if f == 0. {
+                break;
+            }
+            w -= f / (e * (w + 1.) - (w + 2.) * f / (2. * w + 2.));
+        }
+        w
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        LiftingCondensationLevelAlgorithm::Romps,
+        1_349.5,
+        Celsius(6.92),
+        863.22
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(30.), relative_humidity: RelativeHumidity::new(30.).unwrap(), barometric_pressure: BarometricPressure(1_000.) },
+        LiftingCondensationLevelAlgorithm::Romps,
+        2_441.8,
+        Celsius(6.35),
+        751.84
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(10.), relative_humidity: RelativeHumidity::new(90.).unwrap(), barometric_pressure: BarometricPressure(950.) },
+        LiftingCondensationLevelAlgorithm::Romps,
+        197.3,
+        Celsius(8.09),
+        927.66
+    )]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, LiftingCondensationLevelAlgorithm::Romps, 569.5, Celsius(-10.54), 941.67)]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(100.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        LiftingCondensationLevelAlgorithm::Romps,
+        0.,
+        Celsius(25.),
+        1_013.25
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        LiftingCondensationLevelAlgorithm::Romps,
+        1_349.5,
+        Fahrenheit(44.46),
+        863.22
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        LiftingCondensationLevelAlgorithm::Espy,
+        1_343.1,
+        Celsius(6.84),
+        862.76
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(30.), relative_humidity: RelativeHumidity::new(30.).unwrap(), barometric_pressure: BarometricPressure(1_000.) },
+        LiftingCondensationLevelAlgorithm::Espy,
+        2_433.8,
+        Celsius(6.15),
+        750.65
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(100.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        LiftingCondensationLevelAlgorithm::Espy,
+        0.,
+        Celsius(25.),
+        1_013.25
+    )]
+    fn test_lifting_condensation_level_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<T>,
+        #[case] algorithm: LiftingCondensationLevelAlgorithm,
+        #[case] expected_height: f32,
+        #[case] expected_temperature: T,
+        #[case] expected_barometric_pressure: f32,
+    ) {
+        let lcl = input
+            .lifting_condensation_level_with_algorithm(algorithm)
+            .unwrap();
+        assert_relative_eq!(lcl.height().value(), expected_height, epsilon = 1.);
+        assert_relative_eq!(
+            lcl.temperature().value(),
+            expected_temperature.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            lcl.barometric_pressure().value(),
+            expected_barometric_pressure,
+            epsilon = 0.1
+        );
+    }
+
+    #[rstest]
+    fn test_lifting_condensation_level_default_algorithm() {
+        let input = TemperatureRelativeHumidityAndBarometricPressure {
+            temperature: Celsius(20.),
+            relative_humidity: RelativeHumidity::new(50.).unwrap(),
+            barometric_pressure: BarometricPressure(1_013.25),
+        };
+        assert_eq!(
+            input.lifting_condensation_level().unwrap().height(),
+            input
+                .lifting_condensation_level_with_algorithm(LiftingCondensationLevelAlgorithm::Romps)
+                .unwrap()
+                .height()
+        );
+    }
+
+    #[rstest]
+    #[case(LiftingCondensationLevelAlgorithm::Romps)]
+    #[case(LiftingCondensationLevelAlgorithm::Espy)]
+    fn test_lifting_condensation_level_of_dry_air(
+        #[case] algorithm: LiftingCondensationLevelAlgorithm,
+    ) {
+        assert_eq!(
+            TemperatureRelativeHumidityAndBarometricPressure {
+                temperature: Celsius(20.),
+                relative_humidity: RelativeHumidity::new(0.).unwrap(),
+                barometric_pressure: BarometricPressure(1_013.25)
+            }
+            .lifting_condensation_level_with_algorithm(algorithm)
+            .err(),
+            Some("Relative humidity must be above 0 %")
+        );
+    }
+
+    #[rstest]
+    #[case(
+        Celsius(20.),
+        Celsius(9.3),
+        LiftingCondensationLevelAlgorithm::Romps,
+        1_344.,
+        863.8
+    )]
+    #[case(
+        Celsius(20.),
+        Celsius(9.3),
+        LiftingCondensationLevelAlgorithm::Espy,
+        1_337.5,
+        863.34
+    )]
+    #[case(
+        Celsius(25.),
+        Celsius(25.),
+        LiftingCondensationLevelAlgorithm::Romps,
+        0.,
+        1_013.25
+    )]
+    #[case(
+        Fahrenheit(68.),
+        Fahrenheit(48.74),
+        LiftingCondensationLevelAlgorithm::Espy,
+        1_337.5,
+        863.34
+    )]
+    fn test_lifting_condensation_level_from_dew_point<T: Temperature>(
+        #[case] temperature: T,
+        #[case] dew_point: T,
+        #[case] algorithm: LiftingCondensationLevelAlgorithm,
+        #[case] expected_height: f32,
+        #[case] expected_barometric_pressure: f32,
+    ) {
+        let lcl = TemperatureRelativeHumidityAndBarometricPressure::from_dew_point(
+            temperature,
+            dew_point,
+            BarometricPressure(1_013.25),
+        )
+        .unwrap()
+        .lifting_condensation_level_with_algorithm(algorithm)
+        .unwrap();
+        assert_relative_eq!(lcl.height().value(), expected_height, epsilon = 1.);
+        assert_relative_eq!(
+            lcl.barometric_pressure().value(),
+            expected_barometric_pressure,
+            epsilon = 0.1
+        );
+    }
+
+    #[rstest]
+    fn test_from_dew_point_above_temperature() {
+        assert_eq!(
+            TemperatureRelativeHumidityAndBarometricPressure::from_dew_point(
+                Celsius(20.),
+                Celsius(21.),
+                BarometricPressure(1_013.25)
+            )
+            .err(),
+            Some("Dew point must not be above temperature")
+        );
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        Celsius(21.28),
+        Celsius(20.18),
+        Celsius(39.91)
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(30.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(1_000.) },
+        Celsius(33.44),
+        Celsius(33.44),
+        Celsius(86.81)
+    )]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-10.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(850.) }, Celsius(-9.73), Celsius(2.79), Celsius(7.39))]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(100.).unwrap(), barometric_pressure: BarometricPressure(900.) },
+        Celsius(17.08),
+        Celsius(25.95),
+        Celsius(58.7)
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        Fahrenheit(70.31),
+        Fahrenheit(68.32),
+        Fahrenheit(103.83)
+    )]
+    fn test_moist_thermodynamic_temperatures_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<T>,
+        #[case] expected_virtual_temperature: T,
+        #[case] expected_virtual_potential_temperature: T,
+        #[case] expected_equivalent_potential_temperature: T,
+    ) {
+        assert_relative_eq!(
+            input.virtual_temperature().value(),
+            expected_virtual_temperature.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input.virtual_potential_temperature().value(),
+            expected_virtual_potential_temperature.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input.equivalent_potential_temperature().value(),
+            expected_equivalent_potential_temperature.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(10.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        Altitude(1_385.93),
+        Altitude(1_085.93)
+    )]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(5.), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(900.) },
+        Altitude(1_288.17),
+        Altitude(988.17)
+    )]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(2.), relative_humidity: RelativeHumidity::new(90.).unwrap(), barometric_pressure: BarometricPressure(950.) }, Altitude(738.82), Altitude(438.82))]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-2.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(950.) }, Altitude(514.62), Altitude(214.62))]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(50.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) },
+        Altitude(1_385.93),
+        Altitude(1_085.93)
+    )]
+    fn test_wet_bulb_zero_height_and_snow_level_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<T>,
+        #[case] expected_wet_bulb_zero_height: Altitude,
+        #[case] expected_snow_level: Altitude,
+    ) {
+        assert_relative_eq!(
+            input.wet_bulb_zero_height().value(),
+            expected_wet_bulb_zero_height.value(),
+            epsilon = 0.5
+        );
+        assert_relative_eq!(
+            input.snow_level().value(),
+            expected_snow_level.value(),
+            epsilon = 0.5
+        );
+    }
+
+    #[rstest]
+    #[case(Celsius(-50.), 120., Ok(73.35))]
+    #[case(Celsius(-30.), 150., Err("Relative humidity must be between 0 and 100 %"))]
+    #[case(Fahrenheit(-58.), 120., Ok(73.35))]
+    fn test_relative_humidity_over_ice_conversion<T: Temperature>(
+        #[case] temperature: T,
+        #[case] relative_humidity_over_ice: f32,
+        #[case] expected_relative_humidity: Result<f32, &'static str>,
+    ) {
+        match (
+            TemperatureRelativeHumidityAndBarometricPressure::from_relative_humidity_over_ice(
+                temperature,
+                relative_humidity_over_ice,
+                BarometricPressure(250.),
+            ),
+            expected_relative_humidity,
+        ) {
+            (Ok(value), Ok(expected)) => {
+                assert_relative_eq!(value.relative_humidity.value(), expected, epsilon = 0.05)
+            }
+            (value, expected) => assert_eq!(value.err(), expected.err()),
+        }
+    }
+
+    #[rstest]
+    #[case(&[TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(1_000.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(850.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(5.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(700.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-10.), relative_humidity: RelativeHumidity::new(40.).unwrap(), barometric_pressure: BarometricPressure(500.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-35.), relative_humidity: RelativeHumidity::new(30.).unwrap(), barometric_pressure: BarometricPressure(300.) }], Ok(32.16))]
+    #[case(&[TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(1_000.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(850.) }], Ok(16.38))]
+    #[case(&[TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(0.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(1_013.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(900.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-15.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(700.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-30.), relative_humidity: RelativeHumidity::new(30.).unwrap(), barometric_pressure: BarometricPressure(500.) }], Ok(6.92))]
+    #[case(&[TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(77.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(1_000.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(59.), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(850.) }], Ok(16.38))]
+    #[case(&[TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(1_000.) }], Err("A profile needs at least two levels"))]
+    #[case(&[TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(850.) }, TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(1_000.) }], Err("Pressure must strictly decrease with the levels"))]
+    fn test_precipitable_water_computation<T: Temperature>(
+        #[case] profile: &[TemperatureRelativeHumidityAndBarometricPressure<T>],
+        #[case] expected_precipitable_water: Result<PrecipitableWater, &'static str>,
+    ) {
+        match (
+            TemperatureRelativeHumidityAndBarometricPressure::precipitable_water(profile),
+            expected_precipitable_water,
+        ) {
+            (Ok(value), Ok(expected)) => assert_relative_eq!(value, expected, epsilon = 0.01),
+            (value, expected) => assert_eq!(value, expected),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(21.18), relative_humidity: RelativeHumidity::new(45.59).unwrap(), barometric_pressure: BarometricPressure(991.32) },
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(70.12), relative_humidity: RelativeHumidity::new(45.59).unwrap(), barometric_pressure: BarometricPressure(991.32) }
+    )]
+    fn test_temperature_relative_humidity_and_barometric_pressure_celsius_to_fahrenheit_conversion(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<Celsius>,
+        #[case] expected: TemperatureRelativeHumidityAndBarometricPressure<Fahrenheit>,
+    ) {
+        let value: TemperatureRelativeHumidityAndBarometricPressure<Fahrenheit> = input.into();
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(70.12), relative_humidity: RelativeHumidity::new(45.59).unwrap(), barometric_pressure: BarometricPressure(991.32) },
+        TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(21.18), relative_humidity: RelativeHumidity::new(45.59).unwrap(), barometric_pressure: BarometricPressure(991.32) }
+    )]
+    fn test_temperature_relative_humidity_and_barometric_pressure_fahrenheit_to_celsius_conversion(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<Fahrenheit>,
+        #[case] expected: TemperatureRelativeHumidityAndBarometricPressure<Celsius>,
+    ) {
+        let value: TemperatureRelativeHumidityAndBarometricPressure<Celsius> = input.into();
+        assert_eq!(value, expected);
+    }
+}
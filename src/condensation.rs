@@ -0,0 +1,200 @@
+use crate::{Celsius, Fahrenheit, Temperature};
+
+/// The condensation risk on a surface.
+///
+/// The condensation risk compares the temperature of a surface (window frame, cold wall, thermal
+/// bridge, ...) with the dew point of the surrounding air: water condenses on the surface when
+/// its temperature is at or below the dew point.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CondensationRisk<T: Temperature> {
+    surface_temperature: T,
+    dew_point: T,
+}
+
+impl<T: Temperature> CondensationRisk<T> {
+    /// Create a CondensationRisk from the temperature of the surface and the dew point of the
+    /// surrounding air.
+    pub(crate) fn new(surface_temperature: T, dew_point: T) -> Self {
+        Self {
+            surface_temperature,
+            dew_point,
+        }
+    }
+
+    /// Get the dew point of the air.
+    pub fn dew_point(&self) -> T {
+        self.dew_point
+    }
+
+    /// Get the margin between the surface temperature and the dew point (either in °C or °F).
+    ///
+    /// A negative margin means that water condenses on the surface.
+    pub fn margin(&self) -> f32 {
+        self.surface_temperature.value() - self.dew_point.value()
+    }
+
+    /// Get the category of the condensation risk.
+    pub fn category(&self) -> CondensationRiskCategory {
+        let margin = self.surface_temperature.celsius().value() - self.dew_point.celsius().value();
+        if margin <= 0. {
+            CondensationRiskCategory::Condensation
+        } else if margin < 1. {
+            CondensationRiskCategory::High
+        } else if margin < 3. {
+            CondensationRiskCategory::Moderate
+        } else if margin < 5. {
+            CondensationRiskCategory::Low
+        } else {
+            CondensationRiskCategory::NoRisk
+        }
+    }
+}
+
+/// The category of a condensation risk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CondensationRiskCategory {
+    /// No risk: the surface is at least 5°C above the dew point.
+    NoRisk,
+    /// Low risk: the surface is 3°C to 5°C above the dew point.
+    Low,
+    /// Moderate risk: the surface is 1°C to 3°C above the dew point, condensation may occur if
+    /// the surface cools down or the humidity increases.
+    Moderate,
+    /// High risk: the surface is less than 1°C above the dew point.
+    High,
+    /// Condensation: the surface is at or below the dew point.
+    Condensation,
+}
+
+/// The combination of the temperature of the inner side of a building element and of the
+/// indoor and outdoor air temperatures.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SurfaceAndAirTemperatures<T: Temperature> {
+    /// The temperature of the inner surface (either in °C or °F).
+    pub surface_temperature: T,
+    /// The indoor air temperature (either in °C or °F).
+    pub indoor_temperature: T,
+    /// The outdoor air temperature (either in °C or °F).
+    pub outdoor_temperature: T,
+}
+
+impl<T: Temperature> SurfaceAndAirTemperatures<T> {
+    /// Computes the temperature factor at the internal surface (fRsi), as defined by ISO 13788.
+    ///
+    /// The temperature factor is the ratio between the difference of the surface and outdoor
+    /// temperatures and the difference of the indoor and outdoor temperatures. It is 1 for a
+    /// perfectly insulated element and 0 for a surface at the outdoor temperature.
+    ///
+    /// Returns an error if the indoor and outdoor temperatures are equal.
+    pub fn temperature_factor(&self) -> Result<f32, &'static str> {
+        let outdoor_temperature = self.outdoor_temperature.celsius().value();
+        let difference = self.indoor_temperature.celsius().value() - outdoor_temperature;
+        if difference == 0. {
+            return Err("Indoor and outdoor temperatures must be different");
+        }
+        Ok((self.surface_temperature.celsius().value() - outdoor_temperature) / difference)
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for SurfaceAndAirTemperatures<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.surface_temperature.eq(&other.surface_temperature)
+            && self.indoor_temperature.eq(&other.indoor_temperature)
+            && self.outdoor_temperature.eq(&other.outdoor_temperature)
+    }
+}
+
+impl From<SurfaceAndAirTemperatures<Fahrenheit>> for SurfaceAndAirTemperatures<Celsius> {
+    fn from(value: SurfaceAndAirTemperatures<Fahrenheit>) -> Self {
+        Self {
+            surface_temperature: value.surface_temperature.celsius(),
+            indoor_temperature: value.indoor_temperature.celsius(),
+            outdoor_temperature: value.outdoor_temperature.celsius(),
+        }
+    }
+}
+
+impl From<SurfaceAndAirTemperatures<Celsius>> for SurfaceAndAirTemperatures<Fahrenheit> {
+    fn from(value: SurfaceAndAirTemperatures<Celsius>) -> Self {
+        Self {
+            surface_temperature: value.surface_temperature.fahrenheit(),
+            indoor_temperature: value.indoor_temperature.fahrenheit(),
+            outdoor_temperature: value.outdoor_temperature.fahrenheit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{RelativeHumidity, TemperatureAndRelativeHumidity};
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, Celsius(18.), Celsius(9.26), 8.74, CondensationRiskCategory::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, Celsius(13.), Celsius(9.26), 3.74, CondensationRiskCategory::Low)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(60.).unwrap() }, Celsius(13.5), Celsius(12.0), 1.5, CondensationRiskCategory::Moderate)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(60.).unwrap() }, Celsius(12.5), Celsius(12.0), 0.5, CondensationRiskCategory::High)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(70.).unwrap() }, Celsius(10.), Celsius(14.36), -4.36, CondensationRiskCategory::Condensation)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, Fahrenheit(55.4), Fahrenheit(48.66), 6.74, CondensationRiskCategory::Low)]
+    fn test_condensation_risk_computation<T: Temperature>(
+        #[case] air: TemperatureAndRelativeHumidity<T>,
+        #[case] surface_temperature: T,
+        #[case] expected_dew_point: T,
+        #[case] expected_margin: f32,
+        #[case] expected_category: CondensationRiskCategory,
+    ) {
+        let risk = air.condensation_risk(surface_temperature);
+        assert_relative_eq!(
+            risk.dew_point().value(),
+            expected_dew_point.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(risk.margin(), expected_margin, epsilon = 0.01);
+        assert_eq!(risk.category(), expected_category);
+    }
+
+    #[rstest]
+    #[case(SurfaceAndAirTemperatures{ surface_temperature: Celsius(15.), indoor_temperature: Celsius(20.), outdoor_temperature: Celsius(0.) }, Ok(0.75))]
+    #[case(SurfaceAndAirTemperatures{ surface_temperature: Celsius(12.6), indoor_temperature: Celsius(20.), outdoor_temperature: Celsius(-5.) }, Ok(0.704))]
+    #[case(SurfaceAndAirTemperatures{ surface_temperature: Celsius(20.), indoor_temperature: Celsius(20.), outdoor_temperature: Celsius(-10.) }, Ok(1.))]
+    #[case(SurfaceAndAirTemperatures{ surface_temperature: Fahrenheit(59.), indoor_temperature: Fahrenheit(68.), outdoor_temperature: Fahrenheit(32.) }, Ok(0.75))]
+    #[case(SurfaceAndAirTemperatures{ surface_temperature: Celsius(18.), indoor_temperature: Celsius(20.), outdoor_temperature: Celsius(20.) }, Err("Indoor and outdoor temperatures must be different"))]
+    fn test_temperature_factor_computation<T: Temperature>(
+        #[case] input: SurfaceAndAirTemperatures<T>,
+        #[case] expected_temperature_factor: Result<f32, &'static str>,
+    ) {
+        match (input.temperature_factor(), expected_temperature_factor) {
+            (Ok(value), Ok(expected)) => assert_relative_eq!(value, expected, epsilon = 0.01),
+            (value, expected) => assert_eq!(value, expected),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        SurfaceAndAirTemperatures{ surface_temperature: Celsius(15.), indoor_temperature: Celsius(20.), outdoor_temperature: Celsius(0.) },
+        SurfaceAndAirTemperatures{ surface_temperature: Fahrenheit(59.), indoor_temperature: Fahrenheit(68.), outdoor_temperature: Fahrenheit(32.) }
+    )]
+    fn test_surface_and_air_temperatures_celsius_to_fahrenheit_conversion(
+        #[case] input: SurfaceAndAirTemperatures<Celsius>,
+        #[case] expected: SurfaceAndAirTemperatures<Fahrenheit>,
+    ) {
+        let value: SurfaceAndAirTemperatures<Fahrenheit> = input.into();
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(
+        SurfaceAndAirTemperatures{ surface_temperature: Fahrenheit(59.), indoor_temperature: Fahrenheit(68.), outdoor_temperature: Fahrenheit(32.) },
+        SurfaceAndAirTemperatures{ surface_temperature: Celsius(15.), indoor_temperature: Celsius(20.), outdoor_temperature: Celsius(0.) }
+    )]
+    fn test_surface_and_air_temperatures_fahrenheit_to_celsius_conversion(
+        #[case] input: SurfaceAndAirTemperatures<Fahrenheit>,
+        #[case] expected: SurfaceAndAirTemperatures<Celsius>,
+    ) {
+        let value: SurfaceAndAirTemperatures<Celsius> = input.into();
+        assert_eq!(value, expected);
+    }
+}
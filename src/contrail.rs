@@ -0,0 +1,244 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use crate::atmosphere::{EPSILON, KELVIN};
+use crate::psychrometrics::solve;
+use crate::{Celsius, Temperature};
+
+/// The emission index of water vapour of kerosene (in kg per kg of fuel).
+const WATER_VAPOUR_EMISSION_INDEX: f32 = 1.25;
+/// The specific combustion heat of kerosene (in J/kg).
+const COMBUSTION_HEAT: f32 = 43.2e6;
+/// The specific heat capacity of air at constant pressure used by Schumann (1996)
+/// (in J/(kg·K)).
+const SPECIFIC_HEAT_AIR: f32 = 1_004.;
+/// The overall propulsion efficiency of modern jet engines.
+pub(crate) const DEFAULT_PROPULSION_EFFICIENCY: f32 = 0.3;
+/// The lowest pressure of the approximation of the saturated threshold temperature (in hPa).
+///
+/// At lower pressures, the approximation of Schumann (1996) diverges, and its logarithm is not
+/// defined below 8 to 11 hPa, depending on the propulsion efficiency.
+pub(crate) const MINIMUM_CONTRAIL_PRESSURE: f32 = 20.;
+
+/// The contrail formation, following the Schmidt-Appleman criterion.
+///
+/// The exhaust of the engines mixes with the ambient air along a straight line in the
+/// temperature and vapour pressure diagram, whose slope depends on the pressure and on the
+/// propulsion efficiency of the engines. A contrail forms when the mixing line crosses the
+/// saturation over water, i.e. when the temperature is at or below the threshold temperature,
+/// using the formulas of Schumann (1996). It persists when the air is supersaturated with
+/// respect to ice, otherwise the ice crystals evaporate within seconds to minutes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ContrailFormation<T: Temperature> {
+    temperature: T,
+    threshold_temperature: T,
+    saturated_threshold_temperature: T,
+    relative_humidity_over_ice: f32,
+}
+
+impl<T: Temperature> ContrailFormation<T> {
+    /// Create a ContrailFormation from the temperature, the relative humidity over water
+    /// (in %), the pressure (in hPa) and the propulsion efficiency of the engines.
+    pub(crate) fn new(
+        temperature: T,
+        relative_humidity: f32,
+        pressure: f32,
+        propulsion_efficiency: f32,
+    ) -> Self {
+        let celsius = temperature.celsius().value();
+        // The slope of the mixing line (in hPa/K).
+        let slope = WATER_VAPOUR_EMISSION_INDEX * SPECIFIC_HEAT_AIR * pressure
+            / (EPSILON * COMBUSTION_HEAT * (1. - propulsion_efficiency));
+        // The threshold temperature of saturated air, approximation of Schumann (1996) with the
+        // slope in Pa/K.
+        let ln = (100. * slope - 0.053).ln();
+        let saturated_threshold_temperature = -46.46 + 9.43 * ln + 0.72 * ln.powi(2);
+        // The threshold temperature is the temperature of the ambient air whose mixing line
+        // reaches the saturation over water at the saturated threshold temperature.
+        let threshold_temperature = solve(-150., saturated_threshold_temperature, |threshold| {
+            saturation_vapour_pressure_over_water(saturated_threshold_temperature)
+                - relative_humidity / 100. * saturation_vapour_pressure_over_water(threshold)
+                - slope * (saturated_threshold_temperature - threshold)
+        });
+        Self {
+            temperature,
+            threshold_temperature: T::from_celsius(Celsius(threshold_temperature)),
+            saturated_threshold_temperature: T::from_celsius(Celsius(
+                saturated_threshold_temperature,
+            )),
+            relative_humidity_over_ice: relative_humidity
+                * saturation_vapour_pressure_over_water(celsius)
+                / saturation_vapour_pressure_over_ice(celsius),
+        }
+    }
+
+    /// Get the threshold temperature, below which contrails form.
+    pub fn threshold_temperature(&self) -> T {
+        self.threshold_temperature
+    }
+
+    /// Get the threshold temperature of saturated air, the highest threshold temperature at
+    /// this pressure.
+    pub fn saturated_threshold_temperature(&self) -> T {
+        self.saturated_threshold_temperature
+    }
+
+    /// Get the relative humidity with respect to ice (in %).
+    ///
+    /// The relative humidity over ice is higher than the relative humidity over water below
+    /// 0°C, and may exceed 100 %.
+    pub fn relative_humidity_over_ice(&self) -> f32 {
+        self.relative_humidity_over_ice
+    }
+
+    /// Get the category of the contrail.
+    pub fn category(&self) -> ContrailCategory {
+        if self.temperature.celsius().value() > self.threshold_temperature.celsius().value() {
+            ContrailCategory::NoContrail
+        } else if self.relative_humidity_over_ice < 100. {
+            ContrailCategory::ShortLived
+        } else {
+            ContrailCategory::Persistent
+        }
+    }
+}
+
+/// The category of a contrail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContrailCategory {
+    /// No contrail forms: the air is too warm or too dry.
+    NoContrail,
+    /// A short-lived contrail forms: the air is subsaturated with respect to ice.
+    ShortLived,
+    /// A persistent contrail forms: the air is supersaturated with respect to ice, the contrail
+    /// may spread into cirrus clouds.
+    Persistent,
+}
+
+/// Computes the saturation vapour pressure over water (in hPa) at the given temperature (in
+/// °C), using the formula of Sonntag (1994), valid down to -100°C.
+pub(crate) fn saturation_vapour_pressure_over_water(temperature: f32) -> f32 {
+    let temperature = temperature + KELVIN;
+    (-6_096.938 / temperature + 16.635_794 - 2.711_193e-2 * temperature
+        + 1.673_952e-5 * temperature.powi(2)
+        + 2.433_502 * temperature.ln())
+    .exp()
+}
+
+/// Computes the saturation vapour pressure over ice (in hPa) at the given temperature (in °C),
+/// using the formula of Sonntag (1994).
+pub(crate) fn saturation_vapour_pressure_over_ice(temperature: f32) -> f32 {
+    let temperature = temperature + KELVIN;
+    (-6_024.528 / temperature + 24.721_9 + 1.061_386_8e-2 * temperature
+        - 1.319_882_5e-5 * temperature.powi(2)
+        - 0.493_825_77 * temperature.ln())
+    .exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        BarometricPressure, Fahrenheit, RelativeHumidity,
+        TemperatureRelativeHumidityAndBarometricPressure,
+    };
+
+    #[rstest]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-50.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(250.) }, 0.3, (Celsius(-49.13), Celsius(-41.77)), 81.8, ContrailCategory::ShortLived)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-45.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(250.) }, 0.3, (Celsius(-46.88), Celsius(-41.77)), 124.61, ContrailCategory::NoContrail)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-55.), relative_humidity: RelativeHumidity::new(70.).unwrap(), barometric_pressure: BarometricPressure(200.) }, 0.3, (Celsius(-49.97), Celsius(-44.08)), 120.24, ContrailCategory::Persistent)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-60.), relative_humidity: RelativeHumidity::new(10.).unwrap(), barometric_pressure: BarometricPressure(300.) }, 0.3, (Celsius(-49.15), Celsius(-39.85)), 18.03, ContrailCategory::ShortLived)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-47.5), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(250.) }, 0.3, (Celsius(-48.52), Celsius(-41.77)), 95.78, ContrailCategory::NoContrail)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-47.5), relative_humidity: RelativeHumidity::new(60.).unwrap(), barometric_pressure: BarometricPressure(250.) }, 0.4, (Celsius(-47.), Celsius(-40.15)), 95.78, ContrailCategory::ShortLived)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(-58.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(250.) }, 0.3, (Fahrenheit(-56.43), Fahrenheit(-43.19)), 81.8, ContrailCategory::ShortLived)]
+    fn test_contrail_formation_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<T>,
+        #[case] propulsion_efficiency: f32,
+        #[case] expected_threshold_temperatures: (T, T),
+        #[case] expected_relative_humidity_over_ice: f32,
+        #[case] expected_category: ContrailCategory,
+    ) {
+        let contrail = input
+            .contrail_formation_with_propulsion_efficiency(propulsion_efficiency)
+            .unwrap();
+        assert_relative_eq!(
+            contrail.threshold_temperature().value(),
+            expected_threshold_temperatures.0.value(),
+            epsilon = 0.05
+        );
+        assert_relative_eq!(
+            contrail.saturated_threshold_temperature().value(),
+            expected_threshold_temperatures.1.value(),
+            epsilon = 0.05
+        );
+        assert_relative_eq!(
+            contrail.relative_humidity_over_ice(),
+            expected_relative_humidity_over_ice,
+            epsilon = 0.1
+        );
+        assert_eq!(contrail.category(), expected_category);
+    }
+
+    #[rstest]
+    fn test_contrail_formation_default_propulsion_efficiency() {
+        let input = TemperatureRelativeHumidityAndBarometricPressure {
+            temperature: Celsius(-50.),
+            relative_humidity: RelativeHumidity::new(50.).unwrap(),
+            barometric_pressure: BarometricPressure(250.),
+        };
+        assert_relative_eq!(
+            input
+                .contrail_formation()
+                .unwrap()
+                .threshold_temperature()
+                .value(),
+            input
+                .contrail_formation_with_propulsion_efficiency(DEFAULT_PROPULSION_EFFICIENCY)
+                .unwrap()
+                .threshold_temperature()
+                .value()
+        );
+    }
+
+    #[rstest]
+    #[case(250., -0.1, "Propulsion efficiency must be between 0 and 1")]
+    #[case(250., 1., "Propulsion efficiency must be between 0 and 1")]
+    #[case(5., 0.3, "Barometric pressure must be at least 20 hPa")]
+    #[case(19.9, 0.3, "Barometric pressure must be at least 20 hPa")]
+    fn test_contrail_formation_errors(
+        #[case] barometric_pressure: f32,
+        #[case] propulsion_efficiency: f32,
+        #[case] expected_error: &'static str,
+    ) {
+        assert_eq!(
+            TemperatureRelativeHumidityAndBarometricPressure {
+                temperature: Celsius(-50.),
+                relative_humidity: RelativeHumidity::new(50.).unwrap(),
+                barometric_pressure: BarometricPressure(barometric_pressure)
+            }
+            .contrail_formation_with_propulsion_efficiency(propulsion_efficiency)
+            .err(),
+            Some(expected_error)
+        );
+    }
+
+    #[rstest]
+    fn test_contrail_formation_default_propulsion_efficiency_error() {
+        assert_eq!(
+            TemperatureRelativeHumidityAndBarometricPressure {
+                temperature: Celsius(-50.),
+                relative_humidity: RelativeHumidity::new(50.).unwrap(),
+                barometric_pressure: BarometricPressure(5.)
+            }
+            .contrail_formation()
+            .err(),
+            Some("Barometric pressure must be at least 20 hPa")
+        );
+    }
+}
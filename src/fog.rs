@@ -0,0 +1,348 @@
+use crate::{
+    Celsius, Temperature, TemperatureAndRelativeHumidity, TemperatureRelativeHumidityAndWindSpeed,
+    WindSpeed,
+};
+
+/// The horizon over which the trend of the spread is extrapolated (in h).
+const FORECAST_HORIZON: f32 = 3.;
+/// The largest cooling of the surfaces below the air temperature, under a clear sky (in °C).
+const CLEAR_SKY_SURFACE_COOLING: f32 = 3.;
+/// The largest increase of the spread needed for fog, under an overcast sky (in °C).
+const OVERCAST_FOG_PENALTY: f32 = 2.;
+
+/// The cloud cover type (in oktas).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CloudCover(u8);
+
+impl CloudCover {
+    /// Create a CloudCover, checking that the passed value is correct.
+    pub fn new(value: u8) -> Result<Self, &'static str> {
+        value.try_into()
+    }
+
+    /// Get the value of the cloud cover (between 0 and 8 oktas).
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+
+    /// Get the fraction of the sky covered by clouds (between 0 and 1).
+    fn fraction(&self) -> f32 {
+        f32::from(self.0) / 8.
+    }
+}
+
+impl TryFrom<u8> for CloudCover {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 8 {
+            Err("Cloud cover must be between 0 and 8 oktas")
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+/// The fog and dew formation risk.
+///
+/// The risk is based on the spread between the temperature and the dew point, extrapolated
+/// over the next 3 hours when it is closing, and adjusted for the wind speed and the cloud
+/// cover:
+/// - fog needs a saturated air layer, which forms best with light winds (1 to 3 m/s) mixing
+///   the cooled air over some depth, and under a clear sky letting the ground cool down by
+///   radiation;
+/// - dew forms on surfaces cooling down by radiation below the air temperature (by up to 3°C
+///   under a clear sky), with calm winds.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FogAndDewRisk<T: Temperature> {
+    temperature: T,
+    dew_point: T,
+    previous_temperature: T,
+    previous_dew_point: T,
+    hours: f32,
+    wind_speed: WindSpeed,
+    cloud_cover: CloudCover,
+}
+
+impl<T: Temperature> FogAndDewRisk<T> {
+    /// Create a FogAndDewRisk from the current observation, an observation made the given
+    /// number of seconds earlier and the cloud cover.
+    ///
+    /// Returns an error if the elapsed time is zero.
+    pub(crate) fn new(
+        observation: &TemperatureRelativeHumidityAndWindSpeed<T>,
+        previous_observation: &TemperatureAndRelativeHumidity<T>,
+        elapsed: u64,
+        cloud_cover: CloudCover,
+    ) -> Result<Self, &'static str> {
+        if elapsed == 0 {
+            return Err("Elapsed time must be positive");
+        }
+        let dew_point = TemperatureAndRelativeHumidity {
+            temperature: observation.temperature,
+            relative_humidity: observation.relative_humidity,
+        }
+        .dew_point();
+        Ok(Self {
+            temperature: observation.temperature,
+            dew_point,
+            previous_temperature: previous_observation.temperature,
+            previous_dew_point: previous_observation.dew_point(),
+            hours: elapsed as f32 / 3_600.,
+            wind_speed: observation.wind_speed,
+            cloud_cover,
+        })
+    }
+
+    /// Get the dew point of the air.
+    pub fn dew_point(&self) -> T {
+        self.dew_point
+    }
+
+    /// Get the spread between the temperature and the dew point (either in °C or °F).
+    pub fn spread(&self) -> f32 {
+        self.temperature.value() - self.dew_point.value()
+    }
+
+    /// Get the trend of the spread between the temperature and the dew point (either in °C/h
+    /// or °F/h).
+    ///
+    /// A negative trend means that the air is getting closer to saturation.
+    pub fn spread_trend(&self) -> f32 {
+        (self.spread() - (self.previous_temperature.value() - self.previous_dew_point.value()))
+            / self.hours
+    }
+
+    /// Get the fog formation risk.
+    pub fn fog(&self) -> FogAndDewRiskLevel {
+        let wind_speed = self.wind_speed.value();
+        let wind_penalty = if wind_speed < 1. {
+            // Calm air: the water vapour deposits as dew, fog stays shallow.
+            0.5
+        } else if wind_speed <= 3. {
+            0.
+        } else if wind_speed <= 5. {
+            1.5
+        } else {
+            4.
+        };
+        let spread = self.projected_spread()
+            + OVERCAST_FOG_PENALTY * self.cloud_cover.fraction()
+            + wind_penalty;
+        if spread <= 1. {
+            FogAndDewRiskLevel::High
+        } else if spread <= 2. {
+            FogAndDewRiskLevel::Moderate
+        } else if spread <= 3.5 {
+            FogAndDewRiskLevel::Low
+        } else {
+            FogAndDewRiskLevel::NoRisk
+        }
+    }
+
+    /// Get the dew formation risk.
+    pub fn dew(&self) -> FogAndDewRiskLevel {
+        let wind_speed = self.wind_speed.value();
+        let wind_penalty = if wind_speed < 2. {
+            0.
+        } else if wind_speed <= 4. {
+            1.
+        } else {
+            3.
+        };
+        let spread = self.projected_spread()
+            - CLEAR_SKY_SURFACE_COOLING * (1. - self.cloud_cover.fraction())
+            + wind_penalty;
+        if spread <= 0. {
+            FogAndDewRiskLevel::High
+        } else if spread <= 1. {
+            FogAndDewRiskLevel::Moderate
+        } else if spread <= 2.5 {
+            FogAndDewRiskLevel::Low
+        } else {
+            FogAndDewRiskLevel::NoRisk
+        }
+    }
+
+    /// Computes the spread (in °C) extrapolated over the forecast horizon when it is closing.
+    fn projected_spread(&self) -> f32 {
+        let spread = self.temperature.celsius().value() - self.dew_point.celsius().value();
+        let previous_spread =
+            self.previous_temperature.celsius().value() - self.previous_dew_point.celsius().value();
+        let trend = (spread - previous_spread) / self.hours;
+        (spread + trend.min(0.) * FORECAST_HORIZON).max(0.)
+    }
+}
+
+/// The level of a fog or dew formation risk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FogAndDewRiskLevel {
+    /// No risk.
+    NoRisk,
+    /// Low risk.
+    Low,
+    /// Moderate risk.
+    Moderate,
+    /// High risk.
+    High,
+}
+
+/// Computes the night minimum temperature (in °C) from the midday temperature and dew point
+/// (both in °C), the mean night wind speed and cloud cover, using the method of Craddock and
+/// Pritchard (1951).
+pub(crate) fn craddock_pritchard_minimum_temperature(
+    temperature: f32,
+    dew_point: f32,
+    wind_speed: WindSpeed,
+    cloud_cover: CloudCover,
+) -> Celsius {
+    // The correction (in °C), by wind speed (0-12, 13-25 and 26-38 kn) and by cloud cover
+    // (0-2, 2-4, 4-6 and 6-8 oktas).
+    const CORRECTIONS: [[f32; 4]; 3] = [
+        [-2.2, -1.7, -0.6, 0.],
+        [-1.1, 0., 0.6, 1.1],
+        [-0.6, 0., 0.6, 1.1],
+    ];
+    let wind_speed = wind_speed.knots();
+    let row = if wind_speed <= 12.5 {
+        0
+    } else if wind_speed <= 25.5 {
+        1
+    } else {
+        2
+    };
+    let column = match cloud_cover.value() {
+        0..=2 => 0,
+        3..=4 => 1,
+        5..=6 => 2,
+        _ => 3,
+    };
+    Celsius(0.316 * temperature + 0.548 * dew_point - 1.24 + CORRECTIONS[row][column])
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Fahrenheit, RelativeHumidity};
+
+    #[rstest]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(10.), relative_humidity: RelativeHumidity::new(90.).unwrap(), wind_speed: WindSpeed(2.) }, TemperatureAndRelativeHumidity{ temperature: Celsius(14.), relative_humidity: RelativeHumidity::new(70.).unwrap() }, 7_200, 0, (1.57, -1.91), FogAndDewRiskLevel::High, FogAndDewRiskLevel::High)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(10.), relative_humidity: RelativeHumidity::new(90.).unwrap(), wind_speed: WindSpeed(6.) }, TemperatureAndRelativeHumidity{ temperature: Celsius(14.), relative_humidity: RelativeHumidity::new(70.).unwrap() }, 7_200, 8, (1.57, -1.91), FogAndDewRiskLevel::NoRisk, FogAndDewRiskLevel::NoRisk)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(8.), relative_humidity: RelativeHumidity::new(95.).unwrap(), wind_speed: WindSpeed(4.) }, TemperatureAndRelativeHumidity{ temperature: Celsius(9.), relative_humidity: RelativeHumidity::new(92.).unwrap() }, 3_600, 2, (0.75, -0.48), FogAndDewRiskLevel::Moderate, FogAndDewRiskLevel::High)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(12.), relative_humidity: RelativeHumidity::new(85.).unwrap(), wind_speed: WindSpeed(2.) }, TemperatureAndRelativeHumidity{ temperature: Celsius(12.), relative_humidity: RelativeHumidity::new(85.).unwrap() }, 3_600, 4, (2.45, 0.), FogAndDewRiskLevel::Low, FogAndDewRiskLevel::Low)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(12.), relative_humidity: RelativeHumidity::new(80.).unwrap(), wind_speed: WindSpeed(0.5) }, TemperatureAndRelativeHumidity{ temperature: Celsius(12.), relative_humidity: RelativeHumidity::new(80.).unwrap() }, 3_600, 4, (3.35, 0.), FogAndDewRiskLevel::NoRisk, FogAndDewRiskLevel::Low)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(60.).unwrap(), wind_speed: WindSpeed(1.5) }, TemperatureAndRelativeHumidity{ temperature: Celsius(18.), relative_humidity: RelativeHumidity::new(55.).unwrap() }, 10_800, 6, (7.71, -0.49), FogAndDewRiskLevel::NoRisk, FogAndDewRiskLevel::NoRisk)]
+    #[case(TemperatureRelativeHumidityAndWindSpeed{ temperature: Fahrenheit(50.), relative_humidity: RelativeHumidity::new(90.).unwrap(), wind_speed: WindSpeed(2.) }, TemperatureAndRelativeHumidity{ temperature: Fahrenheit(57.2), relative_humidity: RelativeHumidity::new(70.).unwrap() }, 7_200, 0, (2.82, -3.44), FogAndDewRiskLevel::High, FogAndDewRiskLevel::High)]
+    fn test_fog_and_dew_risk_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndWindSpeed<T>,
+        #[case] previous_observation: TemperatureAndRelativeHumidity<T>,
+        #[case] elapsed: u64,
+        #[case] cloud_cover: u8,
+        #[case] expected_spread_and_trend: (f32, f32),
+        #[case] expected_fog: FogAndDewRiskLevel,
+        #[case] expected_dew: FogAndDewRiskLevel,
+    ) {
+        let risk = input
+            .fog_and_dew_risk(
+                &previous_observation,
+                elapsed,
+                CloudCover::new(cloud_cover).unwrap(),
+            )
+            .unwrap();
+        assert_relative_eq!(risk.spread(), expected_spread_and_trend.0, epsilon = 0.01);
+        assert_relative_eq!(
+            risk.spread_trend(),
+            expected_spread_and_trend.1,
+            epsilon = 0.01
+        );
+        assert_eq!(risk.fog(), expected_fog);
+        assert_eq!(risk.dew(), expected_dew);
+    }
+
+    #[rstest]
+    fn test_fog_and_dew_risk_without_elapsed_time() {
+        assert_eq!(
+            TemperatureRelativeHumidityAndWindSpeed {
+                temperature: Celsius(10.),
+                relative_humidity: RelativeHumidity::new(90.).unwrap(),
+                wind_speed: WindSpeed(2.)
+            }
+            .fog_and_dew_risk(
+                &TemperatureAndRelativeHumidity {
+                    temperature: Celsius(14.),
+                    relative_humidity: RelativeHumidity::new(70.).unwrap()
+                },
+                0,
+                CloudCover::new(0).unwrap()
+            )
+            .err(),
+            Some("Elapsed time must be positive")
+        );
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureAndRelativeHumidity{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(50.).unwrap() },
+        WindSpeed::from_knots(5.),
+        0,
+        Celsius(3.85)
+    )]
+    #[case(
+        TemperatureAndRelativeHumidity{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(50.).unwrap() },
+        WindSpeed::from_knots(5.),
+        8,
+        Celsius(6.05)
+    )]
+    #[case(
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(40.).unwrap() },
+        WindSpeed::from_knots(15.),
+        3,
+        Celsius(8.36)
+    )]
+    #[case(
+        TemperatureAndRelativeHumidity{ temperature: Celsius(10.), relative_humidity: RelativeHumidity::new(80.).unwrap() },
+        WindSpeed::from_knots(30.),
+        5,
+        Celsius(6.19)
+    )]
+    #[case(
+        TemperatureAndRelativeHumidity{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(60.).unwrap() },
+        WindSpeed::from_knots(2.),
+        7,
+        Celsius(15.81)
+    )]
+    #[case(
+        TemperatureAndRelativeHumidity{ temperature: Fahrenheit(59.), relative_humidity: RelativeHumidity::new(50.).unwrap() },
+        WindSpeed::from_knots(5.),
+        0,
+        Fahrenheit(38.93)
+    )]
+    fn test_minimum_temperature_computation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] wind_speed: WindSpeed,
+        #[case] cloud_cover: u8,
+        #[case] expected_minimum_temperature: T,
+    ) {
+        assert_relative_eq!(
+            input
+                .minimum_temperature(wind_speed, CloudCover::new(cloud_cover).unwrap())
+                .value(),
+            expected_minimum_temperature.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(0, Ok(CloudCover(0)))]
+    #[case(8, Ok(CloudCover(8)))]
+    #[case(9, Err("Cloud cover must be between 0 and 8 oktas"))]
+    fn test_cloud_cover_validation(
+        #[case] value: u8,
+        #[case] expected: Result<CloudCover, &'static str>,
+    ) {
+        assert_eq!(CloudCover::new(value), expected);
+    }
+}
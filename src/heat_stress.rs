@@ -0,0 +1,529 @@
+use core::ops::Deref;
+
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use crate::{BarometricPressure, Celsius, Fahrenheit, RelativeHumidity, Temperature, WindSpeed};
+
+/// The solar irradiance type (global horizontal irradiance, in W/m²).
+pub type SolarIrradiance = f32;
+
+/// The wet bulb globe temperature (WBGT).
+///
+/// The WBGT is the heat stress index defined by ISO 7243. It combines the air temperature, the
+/// natural wet bulb temperature (which accounts for humidity and wind) and the black globe
+/// temperature (which accounts for radiant heat) into a single value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WetBulbGlobeTemperature<T: Temperature>(T);
+
+impl<T: Temperature> WetBulbGlobeTemperature<T> {
+    /// Get the heat category corresponding to the wet bulb globe temperature.
+    pub fn heat_category(&self) -> HeatCategory {
+        if self.celsius().value() < 25.6 {
+            HeatCategory::NoCategory
+        } else if self.celsius().value() < 27.8 {
+            HeatCategory::Category1
+        } else if self.celsius().value() < 29.4 {
+            HeatCategory::Category2
+        } else if self.celsius().value() < 31.1 {
+            HeatCategory::Category3
+        } else if self.celsius().value() < 32.2 {
+            HeatCategory::Category4
+        } else {
+            HeatCategory::Category5
+        }
+    }
+}
+
+impl<T: Temperature> Deref for WetBulbGlobeTemperature<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The heat category corresponding to a wet bulb globe temperature.
+///
+/// The categories and the associated work/rest cycles are the ones of the US Army TB MED 507
+/// guidance, for acclimatized workers wearing light clothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeatCategory {
+    /// No category: WBGT below 25.6°C (78°F).
+    NoCategory,
+    /// Category 1 (white flag): WBGT between 25.6°C and 27.8°C (78°F and 82°F).
+    Category1,
+    /// Category 2 (green flag): WBGT between 27.8°C and 29.4°C (82°F and 85°F).
+    Category2,
+    /// Category 3 (yellow flag): WBGT between 29.4°C and 31.1°C (85°F and 88°F).
+    Category3,
+    /// Category 4 (red flag): WBGT between 31.1°C and 32.2°C (88°F and 90°F).
+    Category4,
+    /// Category 5 (black flag): WBGT above 32.2°C (90°F).
+    Category5,
+}
+
+impl HeatCategory {
+    /// Get the maximum number of minutes of work per hour for the given work intensity, the
+    /// remaining minutes of the hour being rest.
+    pub fn work_minutes_per_hour(&self, work_intensity: WorkIntensity) -> u8 {
+        match (self, work_intensity) {
+            (HeatCategory::NoCategory, _) => 60,
+            (HeatCategory::Category1, WorkIntensity::Easy) => 60,
+            (HeatCategory::Category1, WorkIntensity::Moderate) => 60,
+            (HeatCategory::Category1, WorkIntensity::Hard) => 40,
+            (HeatCategory::Category2, WorkIntensity::Easy) => 60,
+            (HeatCategory::Category2, WorkIntensity::Moderate) => 50,
+            (HeatCategory::Category2, WorkIntensity::Hard) => 30,
+            (HeatCategory::Category3, WorkIntensity::Easy) => 60,
+            (HeatCategory::Category3, WorkIntensity::Moderate) => 40,
+            (HeatCategory::Category3, WorkIntensity::Hard) => 30,
+            (HeatCategory::Category4, WorkIntensity::Easy) => 60,
+            (HeatCategory::Category4, WorkIntensity::Moderate) => 30,
+            (HeatCategory::Category4, WorkIntensity::Hard) => 20,
+            (HeatCategory::Category5, WorkIntensity::Easy) => 50,
+            (HeatCategory::Category5, WorkIntensity::Moderate) => 20,
+            (HeatCategory::Category5, WorkIntensity::Hard) => 10,
+        }
+    }
+}
+
+/// The intensity of the work performed in a hot environment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkIntensity {
+    /// Easy work: walking on hard surface, weapon maintenance, marksmanship training...
+    Easy,
+    /// Moderate work: walking in loose sand, calisthenics, patrolling...
+    Moderate,
+    /// Hard work: walking in loose sand with load, field assaults...
+    Hard,
+}
+
+/// The temperatures measured by a WBGT meter.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WetBulbGlobeTemperatureMeasurements<T: Temperature> {
+    /// The dry bulb (air) temperature (either in °C or °F).
+    pub dry_bulb_temperature: T,
+    /// The natural wet bulb temperature (either in °C or °F).
+    pub natural_wet_bulb_temperature: T,
+    /// The black globe temperature (either in °C or °F).
+    pub globe_temperature: T,
+}
+
+impl<T: Temperature> WetBulbGlobeTemperatureMeasurements<T> {
+    /// Computes the indoor wet bulb globe temperature (without solar load).
+    ///
+    /// See [`WetBulbGlobeTemperature`].
+    pub fn indoor_wbgt(&self) -> WetBulbGlobeTemperature<T> {
+        WetBulbGlobeTemperature(T::from_celsius(Celsius(
+            0.7 * self.natural_wet_bulb_temperature.celsius().value()
+                + 0.3 * self.globe_temperature.celsius().value(),
+        )))
+    }
+
+    /// Computes the outdoor wet bulb globe temperature (with solar load).
+    ///
+    /// See [`WetBulbGlobeTemperature`].
+    pub fn outdoor_wbgt(&self) -> WetBulbGlobeTemperature<T> {
+        WetBulbGlobeTemperature(T::from_celsius(Celsius(
+            0.7 * self.natural_wet_bulb_temperature.celsius().value()
+                + 0.2 * self.globe_temperature.celsius().value()
+                + 0.1 * self.dry_bulb_temperature.celsius().value(),
+        )))
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for WetBulbGlobeTemperatureMeasurements<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dry_bulb_temperature.eq(&other.dry_bulb_temperature)
+            && self
+                .natural_wet_bulb_temperature
+                .eq(&other.natural_wet_bulb_temperature)
+            && self.globe_temperature.eq(&other.globe_temperature)
+    }
+}
+
+impl From<WetBulbGlobeTemperatureMeasurements<Fahrenheit>>
+    for WetBulbGlobeTemperatureMeasurements<Celsius>
+{
+    fn from(value: WetBulbGlobeTemperatureMeasurements<Fahrenheit>) -> Self {
+        Self {
+            dry_bulb_temperature: value.dry_bulb_temperature.celsius(),
+            natural_wet_bulb_temperature: value.natural_wet_bulb_temperature.celsius(),
+            globe_temperature: value.globe_temperature.celsius(),
+        }
+    }
+}
+
+impl From<WetBulbGlobeTemperatureMeasurements<Celsius>>
+    for WetBulbGlobeTemperatureMeasurements<Fahrenheit>
+{
+    fn from(value: WetBulbGlobeTemperatureMeasurements<Celsius>) -> Self {
+        Self {
+            dry_bulb_temperature: value.dry_bulb_temperature.fahrenheit(),
+            natural_wet_bulb_temperature: value.natural_wet_bulb_temperature.fahrenheit(),
+            globe_temperature: value.globe_temperature.fahrenheit(),
+        }
+    }
+}
+
+/// The standard weather observations needed to estimate the wet bulb globe temperature.
+///
+/// The estimation follows the model of Liljegren et al. (2008), "Modeling the Wet Bulb Globe
+/// Temperature Using Standard Meteorological Measurements", which solves the energy balance of
+/// the natural wet bulb thermometer and of the black globe thermometer.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WetBulbGlobeTemperatureConditions<T: Temperature> {
+    /// The air temperature (either in °C or °F).
+    pub temperature: T,
+    /// The relative humidity (in %).
+    pub relative_humidity: RelativeHumidity,
+    /// The wind speed at 2 m above the ground (in m/s).
+    pub wind_speed: WindSpeed,
+    /// The global horizontal solar irradiance (in W/m²).
+    pub solar_irradiance: SolarIrradiance,
+    /// The cosine of the solar zenith angle (0 or less when the sun is below the horizon).
+    pub cos_solar_zenith_angle: f32,
+    /// The barometric pressure at the station (in hPa).
+    pub barometric_pressure: BarometricPressure,
+}
+
+impl<T: Temperature> WetBulbGlobeTemperatureConditions<T> {
+    /// Estimates the natural wet bulb temperature.
+    pub fn natural_wet_bulb_temperature(&self) -> T {
+        let (solar, fdir) = self.solar_irradiance_and_direct_fraction();
+        let air_temperature = self.temperature.celsius().value() + liljegren::KELVIN;
+        let rh = self.relative_humidity.value() / 100.;
+        let pressure = self.barometric_pressure.value();
+        let vapour_pressure = rh * liljegren::saturation_vapour_pressure(air_temperature);
+        let emissivity = liljegren::atmospheric_emissivity(vapour_pressure);
+        let tan_zenith = if fdir > 0. {
+            (1. - self.cos_solar_zenith_angle * self.cos_solar_zenith_angle).sqrt()
+                / self.cos_solar_zenith_angle
+        } else {
+            0.
+        };
+
+        let mut previous = liljegren::dew_point(vapour_pressure);
+        for _ in 0..liljegren::MAX_ITERATIONS {
+            let reference = 0.5 * (previous + air_temperature);
+            let h = liljegren::cylinder_heat_transfer_coefficient(
+                reference,
+                pressure,
+                self.wind_speed.value(),
+            );
+            let radiation = liljegren::STEFAN_BOLTZMANN
+                * liljegren::EMISSIVITY_WICK
+                * (0.5
+                    * (emissivity * air_temperature.powi(4)
+                        + liljegren::EMISSIVITY_SURFACE * air_temperature.powi(4))
+                    - previous.powi(4))
+                + (1. - liljegren::ALBEDO_WICK)
+                    * solar
+                    * ((1. - fdir)
+                        * (1. + 0.25 * liljegren::DIAMETER_WICK / liljegren::LENGTH_WICK)
+                        + fdir
+                            * (tan_zenith / core::f32::consts::PI
+                                + 0.25 * liljegren::DIAMETER_WICK / liljegren::LENGTH_WICK)
+                        + liljegren::ALBEDO_SURFACE);
+            let wick_vapour_pressure = liljegren::saturation_vapour_pressure(previous);
+            let density = pressure * 100. / (liljegren::R_AIR * reference);
+            let schmidt = liljegren::viscosity(reference)
+                / (density * liljegren::diffusivity(reference, pressure));
+            let current = air_temperature
+                - liljegren::heat_of_evaporation(reference) / liljegren::RATIO
+                    * (wick_vapour_pressure - vapour_pressure)
+                    / (pressure - wick_vapour_pressure)
+                    * (liljegren::PRANDTL / schmidt).powf(0.56)
+                + radiation / h;
+            if (current - previous).abs() <= liljegren::CONVERGENCE {
+                previous = current;
+                break;
+            }
+            previous = 0.9 * previous + 0.1 * current;
+        }
+        T::from_celsius(Celsius(previous - liljegren::KELVIN))
+    }
+
+    /// Estimates the black globe temperature (for a 50.8 mm globe, as in the original model).
+    pub fn globe_temperature(&self) -> T {
+        let (solar, fdir) = self.solar_irradiance_and_direct_fraction();
+        let air_temperature = self.temperature.celsius().value() + liljegren::KELVIN;
+        let rh = self.relative_humidity.value() / 100.;
+        let pressure = self.barometric_pressure.value();
+        let vapour_pressure = rh * liljegren::saturation_vapour_pressure(air_temperature);
+        let emissivity = liljegren::atmospheric_emissivity(vapour_pressure);
+        let direct = if fdir > 0. {
+            fdir * (1. / (2. * self.cos_solar_zenith_angle) - 1.)
+        } else {
+            0.
+        };
+
+        let mut previous = air_temperature;
+        for _ in 0..liljegren::MAX_ITERATIONS {
+            let reference = 0.5 * (previous + air_temperature);
+            let h = liljegren::sphere_heat_transfer_coefficient(
+                reference,
+                pressure,
+                self.wind_speed.value(),
+            );
+            let current = (0.5
+                * (emissivity * air_temperature.powi(4)
+                    + liljegren::EMISSIVITY_SURFACE * air_temperature.powi(4))
+                - h / (liljegren::STEFAN_BOLTZMANN * liljegren::EMISSIVITY_GLOBE)
+                    * (previous - air_temperature)
+                + solar / (2. * liljegren::STEFAN_BOLTZMANN * liljegren::EMISSIVITY_GLOBE)
+                    * (1. - liljegren::ALBEDO_GLOBE)
+                    * (direct + 1. + liljegren::ALBEDO_SURFACE))
+                .powf(0.25);
+            if (current - previous).abs() <= liljegren::CONVERGENCE {
+                previous = current;
+                break;
+            }
+            previous = 0.9 * previous + 0.1 * current;
+        }
+        T::from_celsius(Celsius(previous - liljegren::KELVIN))
+    }
+
+    /// Estimates the temperatures that a WBGT meter would measure in these conditions.
+    pub fn measurements(&self) -> WetBulbGlobeTemperatureMeasurements<T> {
+        WetBulbGlobeTemperatureMeasurements {
+            dry_bulb_temperature: self.temperature,
+            natural_wet_bulb_temperature: self.natural_wet_bulb_temperature(),
+            globe_temperature: self.globe_temperature(),
+        }
+    }
+
+    /// Estimates the outdoor wet bulb globe temperature.
+    ///
+    /// See [`WetBulbGlobeTemperature`].
+    pub fn outdoor_wbgt(&self) -> WetBulbGlobeTemperature<T> {
+        self.measurements().outdoor_wbgt()
+    }
+
+    /// Get the solar irradiance, limited to a physically possible value, and its direct beam
+    /// fraction.
+    fn solar_irradiance_and_direct_fraction(&self) -> (f32, f32) {
+        // Sun below the horizon, or less than half a degree above it.
+        if self.cos_solar_zenith_angle <= 0.0087 || self.solar_irradiance <= 0. {
+            return (0., 0.);
+        }
+        let top_of_atmosphere = liljegren::SOLAR_CONSTANT * self.cos_solar_zenith_angle;
+        let normalized = (self.solar_irradiance / top_of_atmosphere).min(0.85);
+        let fdir = (3. - 1.34 * normalized - 1.65 / normalized)
+            .exp()
+            .clamp(0., 0.9);
+        (normalized * top_of_atmosphere, fdir)
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for WetBulbGlobeTemperatureConditions<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.temperature.eq(&other.temperature)
+            && self.relative_humidity.eq(&other.relative_humidity)
+            && self.wind_speed.eq(&other.wind_speed)
+            && self.solar_irradiance.eq(&other.solar_irradiance)
+            && self
+                .cos_solar_zenith_angle
+                .eq(&other.cos_solar_zenith_angle)
+            && self.barometric_pressure.eq(&other.barometric_pressure)
+    }
+}
+
+/// The physical constants and the helper functions of the Liljegren et al. (2008) model.
+///
+/// Temperatures are in K and pressures in hPa.
+mod liljegren {
+    #[allow(unused_imports)]
+    #[cfg(feature = "no-std")]
+    use micromath::F32Ext;
+
+    pub const KELVIN: f32 = 273.15;
+    pub const MAX_ITERATIONS: usize = 50;
+    pub const CONVERGENCE: f32 = 0.02;
+    pub const STEFAN_BOLTZMANN: f32 = 5.6696e-8;
+    pub const SOLAR_CONSTANT: f32 = 1367.;
+    pub const MIN_WIND_SPEED: f32 = 0.13;
+
+    const CP: f32 = 1003.5;
+    const M_AIR: f32 = 28.97;
+    const M_H2O: f32 = 18.015;
+    pub const R_AIR: f32 = 8314.34 / M_AIR;
+    pub const RATIO: f32 = CP * M_AIR / M_H2O;
+    pub const PRANDTL: f32 = CP / (CP + 1.25 * R_AIR);
+
+    pub const DIAMETER_GLOBE: f32 = 0.0508;
+    pub const EMISSIVITY_GLOBE: f32 = 0.95;
+    pub const ALBEDO_GLOBE: f32 = 0.05;
+    pub const DIAMETER_WICK: f32 = 0.007;
+    pub const LENGTH_WICK: f32 = 0.0254;
+    pub const EMISSIVITY_WICK: f32 = 0.95;
+    pub const ALBEDO_WICK: f32 = 0.4;
+    pub const EMISSIVITY_SURFACE: f32 = 0.999;
+    pub const ALBEDO_SURFACE: f32 = 0.45;
+
+    /// Saturation vapour pressure over water (Buck, 1981), with the moist air enhancement factor,
+    /// as in the reference implementation of Liljegren et al. (2008).
+    pub fn saturation_vapour_pressure(temperature: f32) -> f32 {
+        let y = (temperature - KELVIN) / (temperature - 32.18);
+        1.004 * 6.1121 * (17.502 * y).exp()
+    }
+
+    /// Dew point temperature from the vapour pressure, inverse of [`saturation_vapour_pressure`].
+    pub fn dew_point(vapour_pressure: f32) -> f32 {
+        let z = (vapour_pressure / (1.004 * 6.1121)).ln();
+        KELVIN + 240.97 * z / (17.502 - z)
+    }
+
+    pub fn atmospheric_emissivity(vapour_pressure: f32) -> f32 {
+        0.575 * vapour_pressure.powf(0.143)
+    }
+
+    pub fn viscosity(temperature: f32) -> f32 {
+        const SIGMA: f32 = 3.617;
+        const EPS_KAPPA: f32 = 97.0;
+        let tr = temperature / EPS_KAPPA;
+        let omega = (tr - 2.9) / 0.4 * (-0.034) + 1.048;
+        2.6693e-6 * (M_AIR * temperature).sqrt() / (SIGMA * SIGMA * omega)
+    }
+
+    pub fn thermal_conductivity(temperature: f32) -> f32 {
+        (CP + 1.25 * R_AIR) * viscosity(temperature)
+    }
+
+    pub fn diffusivity(temperature: f32, pressure: f32) -> f32 {
+        let pcrit13 = (36.4f32 * 218.).powf(1. / 3.);
+        let tcrit512 = (132.0f32 * 647.3).powf(5. / 12.);
+        let tcrit12 = (132.0f32 * 647.3).sqrt();
+        let mmix = (1. / M_AIR + 1. / M_H2O).sqrt();
+        3.64e-4 * (temperature / tcrit12).powf(2.334) * pcrit13 * tcrit512 * mmix
+            / (pressure / 1013.25)
+            * 1e-4
+    }
+
+    pub fn heat_of_evaporation(temperature: f32) -> f32 {
+        (313.15 - temperature) / 30. * (-71100.) + 2.4073e6
+    }
+
+    fn reynolds(diameter: f32, temperature: f32, pressure: f32, wind_speed: f32) -> f32 {
+        let density = pressure * 100. / (R_AIR * temperature);
+        wind_speed.max(MIN_WIND_SPEED) * density * diameter / viscosity(temperature)
+    }
+
+    pub fn sphere_heat_transfer_coefficient(
+        temperature: f32,
+        pressure: f32,
+        wind_speed: f32,
+    ) -> f32 {
+        let re = reynolds(DIAMETER_GLOBE, temperature, pressure, wind_speed);
+        let nu = 2.0 + 0.6 * re.sqrt() * PRANDTL.powf(0.3333);
+        nu * thermal_conductivity(temperature) / DIAMETER_GLOBE
+    }
+
+    pub fn cylinder_heat_transfer_coefficient(
+        temperature: f32,
+        pressure: f32,
+        wind_speed: f32,
+    ) -> f32 {
+        let re = reynolds(DIAMETER_WICK, temperature, pressure, wind_speed);
+        let nu = 0.281 * re.powf(0.6) * PRANDTL.powf(0.44);
+        nu * thermal_conductivity(temperature) / DIAMETER_WICK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(WetBulbGlobeTemperatureMeasurements{ dry_bulb_temperature: Celsius(30.), natural_wet_bulb_temperature: Celsius(24.), globe_temperature: Celsius(40.) }, Celsius(28.8), Celsius(27.8))]
+    #[case(WetBulbGlobeTemperatureMeasurements{ dry_bulb_temperature: Celsius(22.), natural_wet_bulb_temperature: Celsius(17.), globe_temperature: Celsius(22.) }, Celsius(18.5), Celsius(18.5))]
+    #[case(WetBulbGlobeTemperatureMeasurements{ dry_bulb_temperature: Fahrenheit(86.), natural_wet_bulb_temperature: Fahrenheit(75.2), globe_temperature: Fahrenheit(104.) }, Fahrenheit(83.84), Fahrenheit(82.04))]
+    fn test_wbgt_computation<T: Temperature>(
+        #[case] input: WetBulbGlobeTemperatureMeasurements<T>,
+        #[case] expected_indoor_wbgt: T,
+        #[case] expected_outdoor_wbgt: T,
+    ) {
+        assert_relative_eq!(
+            input.indoor_wbgt().value(),
+            expected_indoor_wbgt.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input.outdoor_wbgt().value(),
+            expected_outdoor_wbgt.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(Celsius(20.), HeatCategory::NoCategory)]
+    #[case(Celsius(26.), HeatCategory::Category1)]
+    #[case(Celsius(28.), HeatCategory::Category2)]
+    #[case(Celsius(30.), HeatCategory::Category3)]
+    #[case(Celsius(31.5), HeatCategory::Category4)]
+    #[case(Celsius(33.), HeatCategory::Category5)]
+    #[case(Fahrenheit(84.), HeatCategory::Category2)]
+    #[case(Fahrenheit(91.), HeatCategory::Category5)]
+    fn test_heat_category<T: Temperature>(
+        #[case] input: T,
+        #[case] expected_category: HeatCategory,
+    ) {
+        assert_eq!(
+            WetBulbGlobeTemperature(input).heat_category(),
+            expected_category
+        );
+    }
+
+    #[rstest]
+    #[case(HeatCategory::NoCategory, WorkIntensity::Hard, 60)]
+    #[case(HeatCategory::Category1, WorkIntensity::Hard, 40)]
+    #[case(HeatCategory::Category3, WorkIntensity::Moderate, 40)]
+    #[case(HeatCategory::Category4, WorkIntensity::Easy, 60)]
+    #[case(HeatCategory::Category5, WorkIntensity::Easy, 50)]
+    #[case(HeatCategory::Category5, WorkIntensity::Hard, 10)]
+    fn test_work_minutes_per_hour(
+        #[case] category: HeatCategory,
+        #[case] work_intensity: WorkIntensity,
+        #[case] expected_minutes: u8,
+    ) {
+        assert_eq!(
+            category.work_minutes_per_hour(work_intensity),
+            expected_minutes
+        );
+    }
+
+    #[rstest]
+    #[case(WetBulbGlobeTemperatureConditions{ temperature: Celsius(30.), relative_humidity: RelativeHumidity::new(50.).unwrap(), wind_speed: WindSpeed(1.), solar_irradiance: 800., cos_solar_zenith_angle: 0.8, barometric_pressure: BarometricPressure(1013.25) }, Celsius(25.07), Celsius(48.17), Celsius(30.18))]
+    #[case(WetBulbGlobeTemperatureConditions{ temperature: Celsius(35.), relative_humidity: RelativeHumidity::new(40.).unwrap(), wind_speed: WindSpeed(2.), solar_irradiance: 900., cos_solar_zenith_angle: 0.9, barometric_pressure: BarometricPressure(1013.25) }, Celsius(25.89), Celsius(50.21), Celsius(31.66))]
+    #[case(WetBulbGlobeTemperatureConditions{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(80.).unwrap(), wind_speed: WindSpeed(0.5), solar_irradiance: 0., cos_solar_zenith_angle: 0., barometric_pressure: BarometricPressure(1013.25) }, Celsius(22.29), Celsius(23.92), Celsius(22.88))]
+    #[case(WetBulbGlobeTemperatureConditions{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(60.).unwrap(), wind_speed: WindSpeed(3.), solar_irradiance: 500., cos_solar_zenith_angle: 0.5, barometric_pressure: BarometricPressure(1013.25) }, Fahrenheit(62.15), Fahrenheit(84.98), Fahrenheit(67.3))]
+    fn test_wbgt_estimation<T: Temperature>(
+        #[case] input: WetBulbGlobeTemperatureConditions<T>,
+        #[case] expected_natural_wet_bulb: T,
+        #[case] expected_globe: T,
+        #[case] expected_wbgt: T,
+    ) {
+        assert_relative_eq!(
+            input.natural_wet_bulb_temperature().value(),
+            expected_natural_wet_bulb.value(),
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            input.globe_temperature().value(),
+            expected_globe.value(),
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            input.outdoor_wbgt().value(),
+            expected_wbgt.value(),
+            epsilon = 0.1
+        );
+    }
+}
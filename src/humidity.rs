@@ -8,11 +8,24 @@ extern crate std;
 
 use approx::relative_eq;
 
-use crate::{Celsius, Fahrenheit, Temperature};
+use crate::{
+    atmosphere, fog, icing, BarometricPressure, Celsius, CloudCover, ComfortZone, CondensationRisk,
+    Fahrenheit, FeelsLike, IcingRisk, IndoorClimate, PsychrometricState, Temperature,
+    TemperatureRelativeHumidityAndWindSpeed, WindSpeed,
+};
 
 /// The absolute humidity type (in g/m³).
 pub type AbsoluteHumidity = f32;
 
+/// The precipitable water type (in mm).
+pub type PrecipitableWater = f32;
+
+/// The coefficients of the Magnus formula of the saturation vapour pressure over water, as
+/// recommended by the WMO (Sonntag 1990).
+const MAGNUS_PRESSURE: f32 = 6.112;
+const MAGNUS_M: f32 = 17.62;
+const MAGNUS_TN: f32 = 243.12;
+
 /// The relative humidity type (in %).
 #[derive(Clone, Copy, Debug, Default)]
 pub struct RelativeHumidity(f32);
@@ -23,6 +36,11 @@ impl RelativeHumidity {
         value.try_into()
     }
 
+    /// Create a RelativeHumidity from a value known to be between 0 and 100 %.
+    pub(crate) const fn from_valid(value: f32) -> Self {
+        Self(value)
+    }
+
     /// Get the value of the relative humidity (between 0 and 100 %).
     pub fn value(&self) -> f32 {
         self.0
@@ -54,7 +72,7 @@ impl PartialEq for RelativeHumidity {
 /// relative humidity the evaporation rate from the human skin is lower. In that case, the
 /// body cannot dissipate heat as easily as it is the case in dry air.
 /// The heat index is based on subjective measurements and is only meaningful above 25°C and
-/// 40% RH.
+/// 40% RH, except when computed with the [extended](HeatIndexAlgorithm::Extended) algorithm.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct HeatIndex<T: Temperature>(T);
 
@@ -83,6 +101,28 @@ impl<T: Temperature> Deref for HeatIndex<T> {
     }
 }
 
+/// The algorithm used to compute a heat index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatIndexAlgorithm {
+    /// The Rothfusz regression with coefficients converted to °C, preceded by a simple formula
+    /// for the mild conditions, and followed by the low and high humidity adjustments.
+    #[default]
+    Simplified,
+    /// The algorithm of the US National Weather Service, followed step by step in °F: the
+    /// Steadman simple formula is used when its average with the temperature is below 80°F, the
+    /// Rothfusz regression otherwise, followed by either the low or the high humidity
+    /// adjustment. It matches the NWS online heat index calculator.
+    Nws,
+    /// The extended heat index of Lu & Romps (2022), "Extending the heat index", which solves
+    /// the thermoregulatory model of Steadman in all conditions instead of using a regression.
+    /// It stays physically meaningful at all temperatures and humidities, especially in extreme
+    /// heat.
+    /// When the body can no longer shed its metabolic heat and its core temperature rises, the
+    /// extended heat index is far above the air temperature: it reaches 89.5°C at 37.5°C and
+    /// 100 % relative humidity, as with the reference implementation of the authors.
+    Extended,
+}
+
 /// The relative human body comfort corresponding to a heat index.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Comfort {
@@ -98,6 +138,51 @@ pub enum Comfort {
     HeatStrokeImminent,
 }
 
+/// The humidex.
+///
+/// The humidex is the index used by Environment Canada to describe how hot humid weather feels
+/// to the average person. It combines the temperature and the dew point into a single value
+/// expressed in degrees.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Humidex<T: Temperature>(T);
+
+impl<T: Temperature> Humidex<T> {
+    /// Get the degree of comfort corresponding to the humidex.
+    pub fn comfort(&self) -> HumidexComfort {
+        if self.celsius().value() < 30. {
+            HumidexComfort::NoDiscomfort
+        } else if self.celsius().value() < 40. {
+            HumidexComfort::SomeDiscomfort
+        } else if self.celsius().value() <= 45. {
+            HumidexComfort::GreatDiscomfort
+        } else {
+            HumidexComfort::Dangerous
+        }
+    }
+}
+
+impl<T: Temperature> Deref for Humidex<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The degree of comfort corresponding to a humidex, following the Environment Canada
+/// categories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HumidexComfort {
+    /// Little or no discomfort: humidex below 30.
+    NoDiscomfort,
+    /// Some discomfort: humidex between 30 and 40.
+    SomeDiscomfort,
+    /// Great discomfort, avoid exertion: humidex between 40 and 45.
+    GreatDiscomfort,
+    /// Dangerous, heat stroke possible: humidex above 45.
+    Dangerous,
+}
+
 /// The combination of the temperature and the relative humidity.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct TemperatureAndRelativeHumidity<T: Temperature> {
@@ -111,10 +196,7 @@ impl<T: Temperature> TemperatureAndRelativeHumidity<T> {
     /// Computes the absolute humidity value (in g/m³).
     /// The absolute humidity is defined by the mass of water vapor per humid air volume.
     pub fn absolute_humidity(&self) -> AbsoluteHumidity {
-        (6.112
-            * ((17.67 * self.temperature.celsius().value())
-                / (self.temperature.celsius().value() + 243.5))
-                .exp()
+        (atmosphere::saturation_vapour_pressure(self.temperature.celsius().value())
             * self.relative_humidity.value()
             * 2.1674)
             / (273.15 + self.temperature.celsius().value())
@@ -124,18 +206,60 @@ impl<T: Temperature> TemperatureAndRelativeHumidity<T> {
     /// The dew point temperature is defined as the temperature to which the quantity of air must
     /// be cooled down such that, at constant pressure, condensation occurs.
     pub fn dew_point(&self) -> T {
-        const M: f32 = 17.62;
-        const TN: f32 = 243.12;
-        let val = f32::ln(self.relative_humidity.value() / 100.0)
-            + ((M * self.temperature.celsius().value())
-                / (TN + self.temperature.celsius().value()));
-        T::from_celsius(Celsius((TN * val) / (M - val)))
+        T::from_celsius(Celsius(dew_point(
+            self.relative_humidity.value() / 100.
+                * saturation_vapour_pressure(self.temperature.celsius().value()),
+        )))
+    }
+
+    /// Estimates the total precipitable water (in mm) from the surface dew point, using the
+    /// empirical relation of Reitan (1963).
+    ///
+    /// The estimate assumes a typical humidity profile, it is only meaningful for surface
+    /// observations and on average over several days.
+    pub fn precipitable_water(&self) -> PrecipitableWater {
+        10. * (0.1102 + 0.0614 * self.dew_point().celsius().value()).exp()
+    }
+
+    /// Forecasts the night minimum temperature from the midday temperature and relative
+    /// humidity, the mean night wind speed and cloud cover, using the method of Craddock and
+    /// Pritchard (1951).
+    ///
+    /// The method is meant for radiation nights over inland sites, where the minimum
+    /// temperature is compared with the dew point to forecast radiation fog.
+    pub fn minimum_temperature(&self, wind_speed: WindSpeed, cloud_cover: CloudCover) -> T {
+        T::from_celsius(fog::craddock_pritchard_minimum_temperature(
+            self.temperature.celsius().value(),
+            self.dew_point().celsius().value(),
+            wind_speed,
+            cloud_cover,
+        ))
     }
 
-    /// Computes the heat index.
+    /// Computes the heat index, using the [default algorithm](HeatIndexAlgorithm::Simplified).
     ///
     /// See [`HeatIndex`].
     pub fn heat_index(&self) -> HeatIndex<T> {
+        self.heat_index_with_algorithm(HeatIndexAlgorithm::default())
+    }
+
+    /// Computes the heat index, using the given algorithm.
+    ///
+    /// See [`HeatIndex`] and [`HeatIndexAlgorithm`].
+    pub fn heat_index_with_algorithm(&self, algorithm: HeatIndexAlgorithm) -> HeatIndex<T> {
+        match algorithm {
+            HeatIndexAlgorithm::Simplified => self.simplified_heat_index(),
+            HeatIndexAlgorithm::Nws => self.nws_heat_index(),
+            HeatIndexAlgorithm::Extended => HeatIndex(T::from_celsius(Celsius(
+                lu_romps::heat_index(
+                    self.temperature.celsius().value() + lu_romps::KELVIN,
+                    self.relative_humidity.value() / 100.,
+                ) - lu_romps::KELVIN,
+            ))),
+        }
+    }
+
+    fn simplified_heat_index(&self) -> HeatIndex<T> {
         const C1: f32 = -8.784_695;
         const C2: f32 = 1.611_394_2;
         const C3: f32 = 2.338_549;
@@ -169,6 +293,132 @@ impl<T: Temperature> TemperatureAndRelativeHumidity<T> {
         }
         HeatIndex(T::from_celsius(Celsius(heat_index)))
     }
+
+    fn nws_heat_index(&self) -> HeatIndex<T> {
+        const C1: f32 = -42.379;
+        const C2: f32 = 2.049_015_2;
+        const C3: f32 = 10.143_331;
+        const C4: f32 = -0.224_755_4;
+        const C5: f32 = -0.006_837_83;
+        const C6: f32 = -0.054_817_17;
+        const C7: f32 = 0.001_228_74;
+        const C8: f32 = 0.000_852_82;
+        const C9: f32 = -0.000_001_99;
+
+        let temperature = self.temperature.fahrenheit().value();
+        let relative_humidity = self.relative_humidity.value();
+        let mut heat_index =
+            0.5 * (temperature + 61. + (temperature - 68.) * 1.2 + relative_humidity * 0.094);
+        if (heat_index + temperature) / 2. >= 80. {
+            heat_index = C1
+                + C2 * temperature
+                + C3 * relative_humidity
+                + C4 * temperature * relative_humidity
+                + C5 * temperature * temperature
+                + C6 * relative_humidity * relative_humidity
+                + C7 * temperature * temperature * relative_humidity
+                + C8 * temperature * relative_humidity * relative_humidity
+                + C9 * temperature * temperature * relative_humidity * relative_humidity;
+            if relative_humidity < 13. && (80. ..=112.).contains(&temperature) {
+                heat_index -= ((13. - relative_humidity) / 4.)
+                    * ((17. - (temperature - 95.).abs()) / 17.).sqrt();
+            } else if relative_humidity > 85. && (80. ..=87.).contains(&temperature) {
+                heat_index += ((relative_humidity - 85.) / 10.) * ((87. - temperature) / 5.);
+            }
+        }
+        HeatIndex(T::from_celsius(Fahrenheit(heat_index).celsius()))
+    }
+
+    /// Computes the "feels like" temperature, in calm air, using the
+    /// [default heat index algorithm](HeatIndexAlgorithm::Simplified).
+    ///
+    /// As there is no wind, the wind chill never applies. See [`FeelsLike`].
+    pub fn feels_like(&self) -> FeelsLike<T> {
+        self.feels_like_with_algorithm(HeatIndexAlgorithm::default())
+    }
+
+    /// Computes the "feels like" temperature, in calm air, using the given heat index algorithm
+    /// when it is hot.
+    ///
+    /// See [`feels_like()`](Self::feels_like) and [`HeatIndexAlgorithm`].
+    pub fn feels_like_with_algorithm(&self, algorithm: HeatIndexAlgorithm) -> FeelsLike<T> {
+        TemperatureRelativeHumidityAndWindSpeed {
+            temperature: self.temperature,
+            relative_humidity: self.relative_humidity,
+            wind_speed: WindSpeed(0.),
+        }
+        .feels_like_with_algorithm(algorithm)
+    }
+
+    /// Computes the psychrometric state of the air at the given barometric pressure.
+    ///
+    /// See [`PsychrometricState`].
+    pub fn psychrometric_state(
+        &self,
+        barometric_pressure: BarometricPressure,
+    ) -> PsychrometricState<T> {
+        PsychrometricState::from_relative_humidity(
+            self.temperature,
+            self.relative_humidity,
+            barometric_pressure,
+        )
+    }
+
+    /// Computes the condensation risk on a surface of the given temperature, surrounded by the
+    /// air.
+    ///
+    /// See [`CondensationRisk`].
+    pub fn condensation_risk(&self, surface_temperature: T) -> CondensationRisk<T> {
+        CondensationRisk::new(surface_temperature, self.dew_point())
+    }
+
+    /// Classifies the airframe icing risk, the air being the outside air at the flight level.
+    ///
+    /// Supercooled water droplets freeze on the airframe between 0°C and -20°C, when the air is
+    /// close to saturation. The risk is higher near saturation and between 0°C and -10°C.
+    pub fn icing_risk(&self) -> IcingRisk {
+        icing::icing_risk(
+            self.temperature.celsius().value(),
+            self.dew_point().celsius().value(),
+            self.relative_humidity.value(),
+        )
+    }
+
+    /// Looks up the carburettor icing risk of piston engines in an approximation of the chart
+    /// of the UK Civil Aviation Authority, the air being the outside air.
+    ///
+    /// The air cools down in the carburettor venturi, so carburettor icing happens in warm and
+    /// humid air, far above 0°C.
+    pub fn carburettor_icing_risk(&self) -> IcingRisk {
+        icing::carburettor_icing_risk(
+            self.temperature.celsius().value(),
+            self.dew_point().celsius().value(),
+        )
+    }
+
+    /// Classify the indoor climate, using the given comfort zone.
+    ///
+    /// See [`ComfortZone::classify()`].
+    pub fn indoor_climate(&self, comfort_zone: &ComfortZone) -> IndoorClimate {
+        comfort_zone.classify(self)
+    }
+
+    /// Computes the humidex.
+    ///
+    /// See [`Humidex`].
+    pub fn humidex(&self) -> Humidex<T> {
+        let temperature = self.temperature.celsius().value();
+        // Without water vapour, the dew point is -∞ and the vapour pressure is 0.
+        let vapour_pressure = if self.relative_humidity.value() > 0. {
+            let dew_point = self.dew_point().celsius().value();
+            6.11 * (5_417.753 * (1. / 273.16 - 1. / (273.15 + dew_point))).exp()
+        } else {
+            0.
+        };
+        Humidex(T::from_celsius(Celsius(
+            temperature + 0.5555 * (vapour_pressure - 10.),
+        )))
+    }
 }
 
 impl<T: Temperature + PartialEq> PartialEq for TemperatureAndRelativeHumidity<T> {
@@ -196,12 +446,295 @@ impl From<TemperatureAndRelativeHumidity<Celsius>> for TemperatureAndRelativeHum
     }
 }
 
+/// The thermoregulatory model of Lu & Romps (2022) used to compute the extended heat index.
+///
+/// Temperatures are in K, pressures in Pa and the relative humidity is between 0 and 1.
+mod lu_romps {
+    #[allow(unused_imports)]
+    #[cfg(feature = "no-std")]
+    use micromath::F32Ext;
+
+    pub const KELVIN: f32 = 273.15;
+
+    // Thermodynamic constants.
+    const T_TRIP: f32 = 273.16;
+    const P_TRIP: f32 = 611.65;
+    const E0V: f32 = 2.374e6;
+    const E0S: f32 = 0.3337e6;
+    const RGASA: f32 = 287.04;
+    const RGASV: f32 = 461.;
+    const CVA: f32 = 719.;
+    const CVV: f32 = 1418.;
+    const CVL: f32 = 4119.;
+    const CVS: f32 = 1861.;
+    const CPA: f32 = CVA + RGASA;
+    const CPV: f32 = CVV + RGASV;
+
+    // Thermoregulatory parameters.
+    const SIGMA: f32 = 5.67e-8;
+    const EPSILON: f32 = 0.97;
+    const MASS: f32 = 83.6;
+    const HEIGHT: f32 = 1.69;
+    const CPC: f32 = 3492.;
+    const R: f32 = 124.;
+    const Q: f32 = 180.;
+    const PHI_SALT: f32 = 0.9;
+    const TC: f32 = 310.;
+    const P: f32 = 1.013e5;
+    const ETA: f32 = 1.43e-6;
+    const PA0: f32 = 1.6e3;
+    const RS0: f32 = 0.0387;
+    const ZS0: f32 = 52.1;
+    const PHI0: f32 = 0.84;
+    const ZA: f32 = 60.6 / 17.4;
+    const ZA_BAR: f32 = 60.6 / 11.6;
+    const ZA_UN: f32 = 60.6 / 12.3;
+
+    const TOLERANCE: f32 = 1e-4;
+    const MAX_ITERATIONS: usize = 100;
+
+    /// The thermoregulatory state of the body in equilibrium with the air.
+    enum Equilibrium {
+        /// Region I: fraction of the skin covered by clothing.
+        CoveringFraction(f32),
+        /// Regions II & III: heat transfer resistance of the clothing.
+        ClothingResistance(f32),
+        /// Regions IV & V: heat transfer resistance of the skin.
+        SkinResistance(f32),
+        /// Region VI: rate of change of the core temperature.
+        CoreTemperatureRate(f32),
+    }
+
+    /// Saturation vapour pressure over liquid water or ice, consistent with the thermodynamic
+    /// constants of the model of Lu & Romps (2022).
+    fn saturation_vapour_pressure(temperature: f32) -> f32 {
+        if temperature <= 0. {
+            0.
+        } else if temperature < T_TRIP {
+            P_TRIP
+                * (temperature / T_TRIP).powf((CPV - CVS) / RGASV)
+                * ((E0V + E0S - (CVV - CVS) * T_TRIP) / RGASV * (1. / T_TRIP - 1. / temperature))
+                    .exp()
+        } else {
+            P_TRIP
+                * (temperature / T_TRIP).powf((CPV - CVL) / RGASV)
+                * ((E0V - (CVV - CVL) * T_TRIP) / RGASV * (1. / T_TRIP - 1. / temperature)).exp()
+        }
+    }
+
+    /// Latent heat of vaporization of water.
+    fn latent_heat(temperature: f32) -> f32 {
+        E0V + (CVV - CVL) * (temperature - T_TRIP) + RGASV * temperature
+    }
+
+    /// Core vapour pressure.
+    fn core_vapour_pressure() -> f32 {
+        PHI_SALT * saturation_vapour_pressure(TC)
+    }
+
+    /// Respiratory heat loss (in W/m²).
+    fn respiratory_heat_loss(temperature: f32, vapour_pressure: f32) -> f32 {
+        ETA * Q
+            * (CPA * (TC - temperature)
+                + latent_heat(TC) * RGASA / (P * RGASV)
+                    * (core_vapour_pressure() - vapour_pressure))
+    }
+
+    /// Mass transfer resistance through the skin.
+    fn skin_mass_transfer_resistance(skin_resistance: f32) -> f32 {
+        6.0e8 * skin_resistance.powi(5)
+    }
+
+    /// Heat transfer resistance through the air, for the given convective coefficient and
+    /// radiative fraction.
+    fn air_resistance(surface: f32, temperature: f32, hc: f32, phi_rad: f32) -> f32 {
+        let hr = EPSILON
+            * phi_rad
+            * SIGMA
+            * (surface * surface + temperature * temperature)
+            * (surface + temperature);
+        1. / (hc + hr)
+    }
+
+    /// Heat transfer resistance through the air, exposed part of the skin.
+    fn exposed_air_resistance(surface: f32, temperature: f32) -> f32 {
+        air_resistance(surface, temperature, 17.4, 0.85)
+    }
+
+    /// Heat transfer resistance through the air, clothed part of the skin.
+    fn clothed_air_resistance(surface: f32, temperature: f32) -> f32 {
+        air_resistance(surface, temperature, 11.6, 0.79)
+    }
+
+    /// Heat transfer resistance through the air, naked skin.
+    fn naked_air_resistance(surface: f32, temperature: f32) -> f32 {
+        air_resistance(surface, temperature, 12.3, 0.80)
+    }
+
+    /// Find the root of `f` between `a` and `b` by bisection.
+    fn solve(f: impl Fn(f32) -> f32, mut a: f32, mut b: f32) -> f32 {
+        let fa = f(a);
+        let mut fb = f(b);
+        if fa * fb > 0. {
+            return if fa.abs() < fb.abs() { a } else { b };
+        }
+        let mut c = 0.5 * (a + b);
+        for _ in 0..MAX_ITERATIONS {
+            c = 0.5 * (a + b);
+            let fc = f(c);
+            if fb * fc > 0. {
+                b = c;
+                fb = fc;
+            } else {
+                a = c;
+            }
+            if (a - b).abs() < TOLERANCE {
+                break;
+            }
+        }
+        c
+    }
+
+    /// Find the thermoregulatory equilibrium of the body for the given air conditions.
+    fn equilibrium(temperature: f32, relative_humidity: f32) -> Equilibrium {
+        let ta = temperature;
+        let pa = relative_humidity * saturation_vapour_pressure(ta);
+        let pc = core_vapour_pressure();
+        let qv = Q - respiratory_heat_loss(ta, pa);
+        let m = (pc - pa) / (ZS0 + ZA);
+        let m_bar = (pc - pa) / (ZS0 + ZA_BAR);
+        let ts = solve(
+            |ts| (ts - ta) / exposed_air_resistance(ts, ta) + m - (TC - ts) / RS0,
+            (TC.min(ta) - RS0 * m.abs()).max(0.),
+            TC.max(ta) + RS0 * m.abs(),
+        );
+        let tf = solve(
+            |tf| (tf - ta) / clothed_air_resistance(tf, ta) + m_bar - (TC - tf) / RS0,
+            (TC.min(ta) - RS0 * m_bar.abs()).max(0.),
+            TC.max(ta) + RS0 * m_bar.abs(),
+        );
+        let flux1 = qv - (1. - PHI0) * (TC - ts) / RS0;
+        let flux2 = flux1 - PHI0 * (TC - tf) / RS0;
+        if flux1 <= 0. {
+            Equilibrium::CoveringFraction(1. - qv * RS0 / (TC - ts))
+        } else if flux2 <= 0. {
+            let ts_bar = TC - qv * RS0 / PHI0 + (1. / PHI0 - 1.) * (TC - ts);
+            let tf = solve(
+                |tf| {
+                    (tf - ta) / clothed_air_resistance(tf, ta)
+                        + (pc - pa) * (tf - ta)
+                            / ((ZS0 + ZA_BAR) * (tf - ta)
+                                + R * clothed_air_resistance(tf, ta) * (ts_bar - tf))
+                        - (TC - ts_bar) / RS0
+                },
+                ta,
+                ts_bar,
+            );
+            Equilibrium::ClothingResistance(
+                clothed_air_resistance(tf, ta) * (ts_bar - tf) / (tf - ta),
+            )
+        } else {
+            let flux3 = qv - (TC - ta) / naked_air_resistance(TC, ta) - (pc - pa) / ZA_UN;
+            if flux3 < 0. {
+                let ts = solve(
+                    |ts| {
+                        (ts - ta) / naked_air_resistance(ts, ta)
+                            + (pc - pa) / (skin_mass_transfer_resistance((TC - ts) / qv) + ZA_UN)
+                            - qv
+                    },
+                    0.,
+                    TC,
+                );
+                let rs = (TC - ts) / qv;
+                let zs = skin_mass_transfer_resistance(rs);
+                let ps = pc - (pc - pa) * zs / (zs + ZA_UN);
+                if ps > PHI_SALT * saturation_vapour_pressure(ts) {
+                    let ts = solve(
+                        |ts| {
+                            (ts - ta) / naked_air_resistance(ts, ta)
+                                + (PHI_SALT * saturation_vapour_pressure(ts) - pa) / ZA_UN
+                                - qv
+                        },
+                        0.,
+                        TC,
+                    );
+                    Equilibrium::SkinResistance((TC - ts) / qv)
+                } else {
+                    Equilibrium::SkinResistance(rs)
+                }
+            } else {
+                let area = 0.202 * MASS.powf(0.425) * HEIGHT.powf(0.725);
+                Equilibrium::CoreTemperatureRate(flux3 * area / (MASS * CPC))
+            }
+        }
+    }
+
+    /// Relative humidity of the reference air, with a vapour pressure of 1.6 kPa.
+    fn reference_relative_humidity(temperature: f32) -> f32 {
+        PA0 / saturation_vapour_pressure(temperature)
+    }
+
+    /// Computes the extended heat index (in K).
+    pub fn heat_index(temperature: f32, relative_humidity: f32) -> f32 {
+        match equilibrium(temperature, relative_humidity) {
+            Equilibrium::CoveringFraction(phi) => solve(
+                |t| match equilibrium(t, 1.) {
+                    Equilibrium::CoveringFraction(value) => value - phi,
+                    _ => PHI0 - phi,
+                },
+                0.,
+                240.,
+            ),
+            Equilibrium::ClothingResistance(rf) => solve(
+                |t| match equilibrium(t, reference_relative_humidity(t).min(1.)) {
+                    Equilibrium::CoveringFraction(_) => f32::INFINITY,
+                    Equilibrium::ClothingResistance(value) => value - rf,
+                    _ => -rf,
+                },
+                230.,
+                300.,
+            ),
+            Equilibrium::SkinResistance(rs) => solve(
+                |t| match equilibrium(t, reference_relative_humidity(t)) {
+                    Equilibrium::SkinResistance(value) => value - rs,
+                    Equilibrium::CoreTemperatureRate(_) => -rs,
+                    _ => RS0 - rs,
+                },
+                295.,
+                350.,
+            ),
+            Equilibrium::CoreTemperatureRate(rate) => solve(
+                |t| match equilibrium(t, reference_relative_humidity(t)) {
+                    Equilibrium::CoreTemperatureRate(value) => value - rate,
+                    _ => -rate,
+                },
+                340.,
+                1000.,
+            ),
+        }
+    }
+}
+
+/// Computes the saturation vapour pressure over water (in hPa) at the given temperature (in °C),
+/// using the Magnus formula.
+pub(crate) fn saturation_vapour_pressure(temperature: f32) -> f32 {
+    MAGNUS_PRESSURE * (MAGNUS_M * temperature / (MAGNUS_TN + temperature)).exp()
+}
+
+/// Computes the dew point (in °C) corresponding to the given vapour pressure (in hPa), inverting
+/// the Magnus formula.
+pub(crate) fn dew_point(vapour_pressure: f32) -> f32 {
+    let ln = (vapour_pressure / MAGNUS_PRESSURE).ln();
+    MAGNUS_TN * ln / (MAGNUS_M - ln)
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
     use rstest::rstest;
 
     use super::*;
+    use crate::FeelsLikeIndex;
 
     #[rstest]
     #[case(-23.7, Err("Relative humidity must be between 0 and 100 %"))]
@@ -264,6 +797,22 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(25.), relative_humidity: RelativeHumidity(60.) }, 31.12)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(77.), relative_humidity: RelativeHumidity(60.) }, 31.12)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(10.), relative_humidity: RelativeHumidity(80.) }, 16.85)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(0.), relative_humidity: RelativeHumidity(50.) }, 6.35)]
+    fn test_precipitable_water_estimation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_precipitable_water: PrecipitableWater,
+    ) {
+        assert_relative_eq!(
+            input.precipitable_water(),
+            expected_precipitable_water,
+            epsilon = 0.01
+        );
+    }
+
     #[rstest]
     #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(27.), relative_humidity: RelativeHumidity(40.) }, Celsius(26.86), Comfort::NoDiscomfort)]
     #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(29.), relative_humidity: RelativeHumidity(50.) }, Celsius(29.65), Comfort::NoDiscomfort)]
@@ -291,6 +840,130 @@ mod tests {
         assert_eq!(heat_index.comfort(), expected_comfort);
     }
 
+    // Reference values from the NWS heat index chart, which are rounded to the nearest °F.
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(80.), relative_humidity: RelativeHumidity(40.) }, Fahrenheit(80.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(84.), relative_humidity: RelativeHumidity(90.) }, Fahrenheit(98.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(86.), relative_humidity: RelativeHumidity(90.) }, Fahrenheit(105.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(90.), relative_humidity: RelativeHumidity(40.) }, Fahrenheit(91.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(90.), relative_humidity: RelativeHumidity(70.) }, Fahrenheit(106.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(90.), relative_humidity: RelativeHumidity(90.) }, Fahrenheit(122.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(100.), relative_humidity: RelativeHumidity(40.) }, Fahrenheit(109.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(100.), relative_humidity: RelativeHumidity(55.) }, Fahrenheit(124.))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(32.22), relative_humidity: RelativeHumidity(70.) }, Celsius(41.11))]
+    fn test_nws_heat_index_reference_values<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_heat_index: T,
+    ) {
+        assert_relative_eq!(
+            input
+                .heat_index_with_algorithm(HeatIndexAlgorithm::Nws)
+                .fahrenheit()
+                .value(),
+            expected_heat_index.fahrenheit().value(),
+            epsilon = 0.5
+        );
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(60.), relative_humidity: RelativeHumidity(30.) }, Fahrenheit(57.11), Comfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(75.), relative_humidity: RelativeHumidity(80.) }, Fahrenheit(75.96), Comfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(82.), relative_humidity: RelativeHumidity(95.) }, Fahrenheit(93.97), Comfort::SomeDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(110.), relative_humidity: RelativeHumidity(5.) }, Fahrenheit(101.4), Comfort::SomeDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(37.5), relative_humidity: RelativeHumidity(100.) }, Celsius(88.71), Comfort::HeatStrokeImminent)]
+    fn test_nws_heat_index_computation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_heat_index: T,
+        #[case] expected_comfort: Comfort,
+    ) {
+        let heat_index = input.heat_index_with_algorithm(HeatIndexAlgorithm::Nws);
+        assert_relative_eq!(
+            heat_index.value(),
+            expected_heat_index.value(),
+            epsilon = 0.01
+        );
+        assert_eq!(heat_index.comfort(), expected_comfort);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-40.), relative_humidity: RelativeHumidity(20.) }, Celsius(-40.03), Comfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(0.), relative_humidity: RelativeHumidity(50.) }, Celsius(-0.91), Comfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(25.), relative_humidity: RelativeHumidity(50.) }, Celsius(24.98), Comfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(31.), relative_humidity: RelativeHumidity(60.) }, Celsius(34.61), Comfort::SomeDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(32.), relative_humidity: RelativeHumidity(70.) }, Celsius(39.61), Comfort::SomeDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(40.), relative_humidity: RelativeHumidity(10.) }, Celsius(36.8), Comfort::SomeDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(34.), relative_humidity: RelativeHumidity(80.) }, Celsius(63.95), Comfort::HeatStrokeImminent)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(37.5), relative_humidity: RelativeHumidity(100.) }, Celsius(89.54), Comfort::HeatStrokeImminent)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(77.), relative_humidity: RelativeHumidity(50.) }, Fahrenheit(76.96), Comfort::NoDiscomfort)]
+    fn test_extended_heat_index_computation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_heat_index: T,
+        #[case] expected_comfort: Comfort,
+    ) {
+        let heat_index = input.heat_index_with_algorithm(HeatIndexAlgorithm::Extended);
+        assert_relative_eq!(
+            heat_index.value(),
+            expected_heat_index.value(),
+            epsilon = 0.05
+        );
+        assert_eq!(heat_index.comfort(), expected_comfort);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-10.), relative_humidity: RelativeHumidity(70.) }, Celsius(-10.), FeelsLikeIndex::AirTemperature)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.), relative_humidity: RelativeHumidity(50.) }, Celsius(21.), FeelsLikeIndex::AirTemperature)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(32.), relative_humidity: RelativeHumidity(70.) }, Celsius(40.41), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(89.6), relative_humidity: RelativeHumidity(70.) }, Fahrenheit(104.738), FeelsLikeIndex::HeatIndex)]
+    fn test_feels_like_computation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_feels_like: T,
+        #[case] expected_index: FeelsLikeIndex,
+    ) {
+        let feels_like = input.feels_like();
+        assert_relative_eq!(
+            feels_like.value(),
+            expected_feels_like.value(),
+            epsilon = 0.01
+        );
+        assert_eq!(feels_like.index(), expected_index);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.), relative_humidity: RelativeHumidity(50.) }, HeatIndexAlgorithm::Extended, Celsius(21.), FeelsLikeIndex::AirTemperature)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(32.), relative_humidity: RelativeHumidity(70.) }, HeatIndexAlgorithm::Extended, Celsius(39.61), FeelsLikeIndex::HeatIndex)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(110.), relative_humidity: RelativeHumidity(5.) }, HeatIndexAlgorithm::Nws, Fahrenheit(101.4), FeelsLikeIndex::HeatIndex)]
+    fn test_feels_like_with_algorithm_computation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] algorithm: HeatIndexAlgorithm,
+        #[case] expected_feels_like: T,
+        #[case] expected_index: FeelsLikeIndex,
+    ) {
+        let feels_like = input.feels_like_with_algorithm(algorithm);
+        assert_relative_eq!(
+            feels_like.value(),
+            expected_feels_like.value(),
+            epsilon = 0.01
+        );
+        assert_eq!(feels_like.index(), expected_index);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.), relative_humidity: RelativeHumidity(50.) }, Celsius(22.36), HumidexComfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(30.), relative_humidity: RelativeHumidity(40.) }, Celsius(33.92), HumidexComfort::SomeDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(30.), relative_humidity: RelativeHumidity(0.) }, Celsius(24.45), HumidexComfort::NoDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(32.), relative_humidity: RelativeHumidity(60.) }, Celsius(42.53), HumidexComfort::GreatDiscomfort)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(35.), relative_humidity: RelativeHumidity(70.) }, Celsius(51.81), HumidexComfort::Dangerous)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(86.), relative_humidity: RelativeHumidity(70.) }, Fahrenheit(106.16), HumidexComfort::GreatDiscomfort)]
+    fn test_humidex_computation<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_humidex: T,
+        #[case] expected_comfort: HumidexComfort,
+    ) {
+        let humidex = input.humidex();
+        assert_relative_eq!(humidex.value(), expected_humidex.value(), epsilon = 0.01);
+        assert_eq!(humidex.comfort(), expected_comfort);
+    }
+
     #[rstest]
     #[case(
         TemperatureAndRelativeHumidity{ temperature: Celsius(21.18), relative_humidity: RelativeHumidity(45.59) },
@@ -0,0 +1,102 @@
+/// The icing risk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IcingRisk {
+    /// No risk.
+    NoRisk,
+    /// Light icing.
+    Light,
+    /// Moderate icing.
+    Moderate,
+    /// Serious icing.
+    Serious,
+}
+
+/// Classifies the airframe icing risk from the outside air temperature, the dew point (both in
+/// °C) and the relative humidity (in %).
+///
+/// Supercooled water droplets freeze on the airframe between 0°C and -20°C, when the air is
+/// close to saturation (dew point spread up to 3°C or relative humidity of at least 80 %). The
+/// risk is higher near saturation (dew point spread up to 1°C or relative humidity of at least
+/// 95 %) and between 0°C and -10°C, where the droplets are the largest.
+pub(crate) fn icing_risk(temperature: f32, dew_point: f32, relative_humidity: f32) -> IcingRisk {
+    let spread = temperature - dew_point;
+    let moist = spread <= 3. || relative_humidity >= 80.;
+    let saturated = spread <= 1. || relative_humidity >= 95.;
+    if !(-20.0..=0.0).contains(&temperature) || !moist {
+        IcingRisk::NoRisk
+    } else if temperature >= -10. {
+        if saturated {
+            IcingRisk::Serious
+        } else {
+            IcingRisk::Moderate
+        }
+    } else if saturated {
+        IcingRisk::Moderate
+    } else {
+        IcingRisk::Light
+    }
+}
+
+/// Looks up the carburettor icing risk from the outside air temperature and the dew point (both
+/// in °C), in an approximation of the carburettor icing chart of the UK Civil Aviation
+/// Authority (Safety Sense leaflet 14):
+/// - serious icing at any power between -8°C and 21°C, with a dew point spread up to 4°C;
+/// - moderate icing at cruise power (serious icing at descent power) between -10°C and 30°C,
+///   with a dew point spread up to 9°C;
+/// - light icing at cruise or descent power between -10°C and 38°C, with a dew point spread up
+///   to 17°C.
+pub(crate) fn carburettor_icing_risk(temperature: f32, dew_point: f32) -> IcingRisk {
+    let spread = temperature - dew_point;
+    if (-8.0..=21.0).contains(&temperature) && spread <= 4. {
+        IcingRisk::Serious
+    } else if (-10.0..=30.0).contains(&temperature) && spread <= 9. {
+        IcingRisk::Moderate
+    } else if (-10.0..=38.0).contains(&temperature) && spread <= 17. {
+        IcingRisk::Light
+    } else {
+        IcingRisk::NoRisk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        Celsius, Fahrenheit, RelativeHumidity, Temperature, TemperatureAndRelativeHumidity,
+    };
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(95.).unwrap() }, IcingRisk::Serious)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(85.).unwrap() }, IcingRisk::Moderate)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(60.).unwrap() }, IcingRisk::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-15.), relative_humidity: RelativeHumidity::new(97.).unwrap() }, IcingRisk::Moderate)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-15.), relative_humidity: RelativeHumidity::new(90.).unwrap() }, IcingRisk::Light)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-15.), relative_humidity: RelativeHumidity::new(70.).unwrap() }, IcingRisk::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(2.), relative_humidity: RelativeHumidity::new(100.).unwrap() }, IcingRisk::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-25.), relative_humidity: RelativeHumidity::new(100.).unwrap() }, IcingRisk::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(23.), relative_humidity: RelativeHumidity::new(95.).unwrap() }, IcingRisk::Serious)]
+    fn test_icing_risk_classification<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_risk: IcingRisk,
+    ) {
+        assert_eq!(input.icing_risk(), expected_risk);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(15.), relative_humidity: RelativeHumidity::new(80.).unwrap() }, IcingRisk::Serious)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-9.), relative_humidity: RelativeHumidity::new(75.).unwrap() }, IcingRisk::Moderate)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(60.).unwrap() }, IcingRisk::Moderate)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(5.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, IcingRisk::Light)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(35.), relative_humidity: RelativeHumidity::new(40.).unwrap() }, IcingRisk::Light)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(30.), relative_humidity: RelativeHumidity::new(20.).unwrap() }, IcingRisk::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-15.), relative_humidity: RelativeHumidity::new(90.).unwrap() }, IcingRisk::NoRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(59.), relative_humidity: RelativeHumidity::new(80.).unwrap() }, IcingRisk::Serious)]
+    fn test_carburettor_icing_risk_classification<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_risk: IcingRisk,
+    ) {
+        assert_eq!(input.carburettor_icing_risk(), expected_risk);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{Celsius, RelativeHumidity, Temperature, TemperatureAndRelativeHumidity};
+
+/// A comfort zone on the temperature/relative humidity plane.
+///
+/// The comfort zone is a polygon whose vertices are given in order (either clockwise or
+/// counterclockwise), the last vertex being implicitly connected to the first one.
+#[derive(Clone, Copy, Debug)]
+pub struct ComfortZone<'a> {
+    /// The vertices of the polygon.
+    pub vertices: &'a [(Celsius, RelativeHumidity)],
+    /// The relative humidity above which there is a risk of mould growth.
+    pub mould_risk_relative_humidity: RelativeHumidity,
+}
+
+impl ComfortZone<'static> {
+    /// An approximation of the ASHRAE Standard 55 comfort zone, for typical indoor clothing
+    /// (0.5 to 1.0 clo) and sedentary activity (1.1 met) in still air.
+    ///
+    /// Its humidity limits are a dew point of 2°C and a humidity ratio of 0.012 kg/kg.
+    pub const ASHRAE_55: Self = Self {
+        vertices: &[
+            (Celsius(20.5), RelativeHumidity::from_valid(30.)),
+            (Celsius(27.), RelativeHumidity::from_valid(20.)),
+            (Celsius(26.), RelativeHumidity::from_valid(57.)),
+            (Celsius(19.5), RelativeHumidity::from_valid(84.)),
+        ],
+        mould_risk_relative_humidity: RelativeHumidity::from_valid(80.),
+    };
+
+    /// The comfort zone commonly shown on consumer hygrometers: between 20°C and 26°C, and
+    /// between 40% and 60% RH.
+    pub const HYGROMETER: Self = Self {
+        vertices: &[
+            (Celsius(20.), RelativeHumidity::from_valid(40.)),
+            (Celsius(26.), RelativeHumidity::from_valid(40.)),
+            (Celsius(26.), RelativeHumidity::from_valid(60.)),
+            (Celsius(20.), RelativeHumidity::from_valid(60.)),
+        ],
+        mould_risk_relative_humidity: RelativeHumidity::from_valid(70.),
+    };
+}
+
+impl ComfortZone<'_> {
+    /// Check whether the given temperature and relative humidity are inside the comfort zone.
+    pub fn contains<T: Temperature>(&self, value: &TemperatureAndRelativeHumidity<T>) -> bool {
+        let temperature = value.temperature.celsius().value();
+        let relative_humidity = value.relative_humidity.value();
+        let mut inside = false;
+        let mut previous = match self.vertices.last() {
+            Some(vertex) => vertex,
+            None => return false,
+        };
+        for vertex in self.vertices {
+            let (t1, rh1) = (previous.0.value(), previous.1.value());
+            let (t2, rh2) = (vertex.0.value(), vertex.1.value());
+            if (rh1 > relative_humidity) != (rh2 > relative_humidity)
+                && temperature < t1 + (relative_humidity - rh1) * (t2 - t1) / (rh2 - rh1)
+            {
+                inside = !inside;
+            }
+            previous = vertex;
+        }
+        inside
+    }
+
+    /// Classify the indoor climate for the given temperature and relative humidity.
+    ///
+    /// A relative humidity above the mould risk threshold is always a mould risk. Otherwise, the
+    /// climate is too cold or too warm outside of the temperature range of the comfort zone, and
+    /// too dry or too humid outside of its relative humidity range at this temperature.
+    pub fn classify<T: Temperature>(
+        &self,
+        value: &TemperatureAndRelativeHumidity<T>,
+    ) -> IndoorClimate {
+        let temperature = value.temperature.celsius().value();
+        let relative_humidity = value.relative_humidity.value();
+        if relative_humidity >= self.mould_risk_relative_humidity.value() {
+            return IndoorClimate::MouldRisk;
+        }
+        let (min_temperature, max_temperature) = self.temperature_range();
+        let (min_relative_humidity, max_relative_humidity) =
+            self.relative_humidity_range_at(temperature);
+        if temperature < min_temperature {
+            IndoorClimate::TooCold
+        } else if temperature > max_temperature {
+            IndoorClimate::TooWarm
+        } else if relative_humidity < min_relative_humidity {
+            IndoorClimate::TooDry
+        } else if relative_humidity > max_relative_humidity {
+            IndoorClimate::TooHumid
+        } else {
+            IndoorClimate::Comfortable
+        }
+    }
+
+    /// Get the minimum and maximum temperature of the comfort zone.
+    fn temperature_range(&self) -> (f32, f32) {
+        self.vertices
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), (t, _)| {
+                (min.min(t.value()), max.max(t.value()))
+            })
+    }
+
+    /// Get the minimum and maximum relative humidity of the comfort zone at the given
+    /// temperature.
+    fn relative_humidity_range_at(&self, temperature: f32) -> (f32, f32) {
+        let mut range = (f32::INFINITY, f32::NEG_INFINITY);
+        let mut previous = match self.vertices.last() {
+            Some(vertex) => vertex,
+            None => return range,
+        };
+        for vertex in self.vertices {
+            let (t1, rh1) = (previous.0.value(), previous.1.value());
+            let (t2, rh2) = (vertex.0.value(), vertex.1.value());
+            if t1.min(t2) <= temperature && temperature <= t1.max(t2) {
+                let crossings = if t1 == t2 {
+                    (rh1.min(rh2), rh1.max(rh2))
+                } else {
+                    let rh = rh1 + (temperature - t1) * (rh2 - rh1) / (t2 - t1);
+                    (rh, rh)
+                };
+                range = (range.0.min(crossings.0), range.1.max(crossings.1));
+            }
+            previous = vertex;
+        }
+        range
+    }
+}
+
+/// The indoor climate corresponding to a temperature and a relative humidity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndoorClimate {
+    /// Inside the comfort zone.
+    Comfortable,
+    /// Colder than the comfort zone.
+    TooCold,
+    /// Warmer than the comfort zone.
+    TooWarm,
+    /// Drier than the comfort zone.
+    TooDry,
+    /// More humid than the comfort zone.
+    TooHumid,
+    /// Humid enough for mould to grow.
+    MouldRisk,
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Fahrenheit;
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, IndoorClimate::Comfortable)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(75.).unwrap() }, IndoorClimate::Comfortable)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(26.5), relative_humidity: RelativeHumidity::new(25.).unwrap() }, IndoorClimate::Comfortable)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(17.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, IndoorClimate::TooCold)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(28.), relative_humidity: RelativeHumidity::new(40.).unwrap() }, IndoorClimate::TooWarm)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(15.).unwrap() }, IndoorClimate::TooDry)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(25.), relative_humidity: RelativeHumidity::new(70.).unwrap() }, IndoorClimate::TooHumid)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(24.), relative_humidity: RelativeHumidity::new(70.).unwrap() }, IndoorClimate::TooHumid)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(75.).unwrap() }, IndoorClimate::TooHumid)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(29.), relative_humidity: RelativeHumidity::new(55.).unwrap() }, IndoorClimate::TooWarm)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(24.), relative_humidity: RelativeHumidity::new(85.).unwrap() }, IndoorClimate::MouldRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(19.7), relative_humidity: RelativeHumidity::new(82.).unwrap() }, IndoorClimate::MouldRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(59.), relative_humidity: RelativeHumidity::new(10.).unwrap() }, IndoorClimate::TooCold)]
+    fn test_ashrae_55_classification<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_climate: IndoorClimate,
+    ) {
+        assert_eq!(ComfortZone::ASHRAE_55.classify(&input), expected_climate);
+        assert_eq!(
+            input.indoor_climate(&ComfortZone::ASHRAE_55),
+            expected_climate
+        );
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, IndoorClimate::Comfortable)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(18.), relative_humidity: RelativeHumidity::new(50.).unwrap() }, IndoorClimate::TooCold)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(28.), relative_humidity: RelativeHumidity::new(35.).unwrap() }, IndoorClimate::TooWarm)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(30.).unwrap() }, IndoorClimate::TooDry)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(65.).unwrap() }, IndoorClimate::TooHumid)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(22.), relative_humidity: RelativeHumidity::new(75.).unwrap() }, IndoorClimate::MouldRisk)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(75.2), relative_humidity: RelativeHumidity::new(45.).unwrap() }, IndoorClimate::Comfortable)]
+    fn test_hygrometer_classification<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_climate: IndoorClimate,
+    ) {
+        assert_eq!(ComfortZone::HYGROMETER.classify(&input), expected_climate);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.8), relative_humidity: RelativeHumidity::new(45.).unwrap() }, IndoorClimate::Comfortable)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(19.), relative_humidity: RelativeHumidity::new(45.).unwrap() }, IndoorClimate::TooCold)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(24.), relative_humidity: RelativeHumidity::new(30.).unwrap() }, IndoorClimate::TooWarm)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.), relative_humidity: RelativeHumidity::new(25.).unwrap() }, IndoorClimate::TooDry)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.), relative_humidity: RelativeHumidity::new(60.).unwrap() }, IndoorClimate::TooHumid)]
+    fn test_custom_comfort_zone_classification<T: Temperature>(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] expected_climate: IndoorClimate,
+    ) {
+        let vertices = [
+            (Celsius(20.), RelativeHumidity::new(30.).unwrap()),
+            (Celsius(23.), RelativeHumidity::new(30.).unwrap()),
+            (Celsius(22.), RelativeHumidity::new(55.).unwrap()),
+        ];
+        let zone = ComfortZone {
+            vertices: &vertices,
+            mould_risk_relative_humidity: RelativeHumidity::new(80.).unwrap(),
+        };
+        assert_eq!(zone.classify(&input), expected_climate);
+    }
+
+    #[rstest]
+    fn test_empty_comfort_zone() {
+        let zone = ComfortZone {
+            vertices: &[],
+            mould_risk_relative_humidity: RelativeHumidity::new(80.).unwrap(),
+        };
+        let input = TemperatureAndRelativeHumidity {
+            temperature: Celsius(21.),
+            relative_humidity: RelativeHumidity::new(45.).unwrap(),
+        };
+        assert!(!zone.contains(&input));
+    }
+}
@@ -2,15 +2,69 @@
 #![deny(unsafe_code, missing_docs)]
 #![no_std]
 
+/// The apparent temperature types.
+pub mod apparent_temperature;
+/// The atmospheric thermodynamics types.
+pub mod atmosphere;
+/// The condensation types.
+pub mod condensation;
+/// The contrail types.
+pub mod contrail;
+/// The fog and dew types.
+pub mod fog;
+/// The heat stress types.
+pub mod heat_stress;
 /// The humidity types.
 pub mod humidity;
+/// The icing types.
+pub mod icing;
+/// The indoor climate types.
+pub mod indoor_climate;
+/// The mould growth types.
+pub mod mould;
+/// The precipitation types.
+pub mod precipitation;
 /// The pressure types.
 pub mod pressure;
+/// The psychrometric types.
+pub mod psychrometrics;
+/// The atmospheric sounding types.
+pub mod sounding;
 /// The temperature types (Celsius and Fahrenheit).
 pub mod temperature;
+/// The thermal comfort types.
+pub mod thermal_comfort;
+/// The wind types.
+pub mod wind;
 
+pub use apparent_temperature::{
+    FeelsLike, FeelsLikeIndex, TemperatureRelativeHumidityAndWindSpeed,
+};
+pub use atmosphere::{
+    LiftingCondensationLevel, LiftingCondensationLevelAlgorithm,
+    TemperatureRelativeHumidityAndBarometricPressure,
+};
+pub use condensation::{CondensationRisk, CondensationRiskCategory, SurfaceAndAirTemperatures};
+pub use contrail::{ContrailCategory, ContrailFormation};
+pub use fog::{CloudCover, FogAndDewRisk, FogAndDewRiskLevel};
+pub use heat_stress::{
+    HeatCategory, SolarIrradiance, WetBulbGlobeTemperature, WetBulbGlobeTemperatureConditions,
+    WetBulbGlobeTemperatureMeasurements, WorkIntensity,
+};
 pub use humidity::{
-    AbsoluteHumidity, Comfort, HeatIndex, RelativeHumidity, TemperatureAndRelativeHumidity,
+    AbsoluteHumidity, Comfort, HeatIndex, HeatIndexAlgorithm, Humidex, HumidexComfort,
+    PrecipitableWater, RelativeHumidity, TemperatureAndRelativeHumidity,
 };
+pub use icing::IcingRisk;
+pub use indoor_climate::{ComfortZone, IndoorClimate};
+pub use mould::{MouldGrowth, MouldGrowthLevel, MouldIndex, MouldSensitivity};
+pub use precipitation::{PrecipitationType, PrecipitationTypeProbabilities};
 pub use pressure::{Altitude, BarometricPressure, TemperatureAndBarometricPressure};
+pub use psychrometrics::{AirFlow, PsychrometricProcess, PsychrometricState};
+pub use sounding::{ParcelAnalysis, ParcelType, Sounding, SoundingLevel};
 pub use temperature::{Celsius, Fahrenheit, Temperature};
+pub use thermal_comfort::{
+    AdaptiveComfortCategory, ComfortBand, PredictedMeanVote, RunningMeanOutdoorTemperature,
+    ThermalEnvironment, ThermalEnvironmentCategory,
+};
+pub use wind::{FrostbiteRisk, TemperatureAndWindSpeed, WindChill, WindSpeed};
@@ -0,0 +1,423 @@
+use core::ops::Deref;
+
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use crate::{Temperature, TemperatureAndRelativeHumidity};
+
+/// The coefficient of the exponential slowing down of the growth near the maximum mould index.
+const SLOWDOWN: f32 = 2.3;
+
+/// The mould index.
+///
+/// The mould index describes the mould growth on a surface, from 0 (no growth) to 6 (heavy
+/// and tight growth covering the whole surface), following the scale of the VTT model.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MouldIndex(f32);
+
+impl MouldIndex {
+    /// Get the value of the mould index (between 0 and 6).
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+
+    /// Get the level of mould growth corresponding to the mould index.
+    pub fn level(&self) -> MouldGrowthLevel {
+        if self.0 < 1. {
+            MouldGrowthLevel::NoGrowth
+        } else if self.0 < 2. {
+            MouldGrowthLevel::InitialMicroscopic
+        } else if self.0 < 3. {
+            MouldGrowthLevel::MicroscopicColonies
+        } else if self.0 < 4. {
+            MouldGrowthLevel::VisibleLowCoverage
+        } else if self.0 < 5. {
+            MouldGrowthLevel::VisibleMediumCoverage
+        } else if self.0 < 6. {
+            MouldGrowthLevel::VisibleHighCoverage
+        } else {
+            MouldGrowthLevel::FullCoverage
+        }
+    }
+}
+
+impl Deref for MouldIndex {
+    type Target = f32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The level of mould growth corresponding to a mould index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouldGrowthLevel {
+    /// Mould index below 1: no growth.
+    NoGrowth,
+    /// Mould index between 1 and 2: small amounts of mould, visible only under a microscope.
+    InitialMicroscopic,
+    /// Mould index between 2 and 3: several local colonies, visible only under a microscope.
+    MicroscopicColonies,
+    /// Mould index between 3 and 4: visible growth, covering less than 10% of the surface.
+    VisibleLowCoverage,
+    /// Mould index between 4 and 5: visible growth, covering 10% to 50% of the surface.
+    VisibleMediumCoverage,
+    /// Mould index between 5 and 6: visible growth, covering more than 50% of the surface.
+    VisibleHighCoverage,
+    /// Mould index of 6: heavy and tight growth, covering the whole surface.
+    FullCoverage,
+}
+
+/// The sensitivity of a material to mould growth, as defined by the updated VTT model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MouldSensitivity {
+    /// Very sensitive materials, such as untreated pine sapwood.
+    #[default]
+    VerySensitive,
+    /// Sensitive materials, such as planed wood, paper-coated products and wood-based boards.
+    Sensitive,
+    /// Medium resistant materials, such as cement or plastic based materials and mineral
+    /// fibres.
+    MediumResistant,
+    /// Resistant materials, such as glass and metal products.
+    Resistant,
+}
+
+impl MouldSensitivity {
+    /// Get the relative growth intensity coefficients, before and after the mould index
+    /// reaches 1.
+    fn growth_coefficients(&self) -> (f32, f32) {
+        match self {
+            Self::VerySensitive => (1., 2.),
+            Self::Sensitive => (0.578, 0.386),
+            Self::MediumResistant => (0.072, 0.097),
+            Self::Resistant => (0.033, 0.014),
+        }
+    }
+
+    /// Get the coefficients A, B and C of the maximum mould index.
+    fn maximum_index_coefficients(&self) -> (f32, f32, f32) {
+        match self {
+            Self::VerySensitive => (1., 7., 2.),
+            Self::Sensitive => (0.3, 6., 1.),
+            Self::MediumResistant => (0., 5., 1.5),
+            Self::Resistant => (0., 3., 1.),
+        }
+    }
+
+    /// Get the minimum relative humidity needed for mould growth (in %).
+    fn minimum_relative_humidity(&self) -> f32 {
+        match self {
+            Self::VerySensitive | Self::Sensitive => 80.,
+            Self::MediumResistant | Self::Resistant => 85.,
+        }
+    }
+
+    /// Get the relative decline coefficient, used when conditions are unfavourable to mould
+    /// growth.
+    fn decline_coefficient(&self) -> f32 {
+        match self {
+            Self::VerySensitive => 1.,
+            Self::Sensitive => 0.5,
+            Self::MediumResistant => 0.25,
+            Self::Resistant => 0.1,
+        }
+    }
+}
+
+/// The mould growth model.
+///
+/// The mould growth model computes the [`MouldIndex`] of a surface from a timestamped series of
+/// temperature and relative humidity, using the VTT model of Hukka and Viitanen (1999), updated
+/// by Ojanen et al. (2010) with material sensitivity classes. Mould grows when the relative
+/// humidity stays above a critical value depending on the temperature, and declines slowly when
+/// conditions become unfavourable.
+/// The conditions of each measurement are assumed to last until the next measurement.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MouldGrowth<T: Temperature> {
+    sensitivity: MouldSensitivity,
+    index: f32,
+    unfavourable_hours: f32,
+    last_measurement: Option<(u64, TemperatureAndRelativeHumidity<T>)>,
+}
+
+impl<T: Temperature> MouldGrowth<T> {
+    /// Create a MouldGrowth model for a material of the given sensitivity, with no mould.
+    pub fn new(sensitivity: MouldSensitivity) -> Self {
+        Self {
+            sensitivity,
+            index: 0.,
+            unfavourable_hours: 0.,
+            last_measurement: None,
+        }
+    }
+
+    /// Get the current mould index.
+    pub fn index(&self) -> MouldIndex {
+        MouldIndex(self.index)
+    }
+
+    /// Update the model with a new measurement, taken at the given timestamp (in seconds).
+    ///
+    /// Returns an error if the timestamp is before the one of the previous measurement.
+    pub fn update(
+        &mut self,
+        timestamp: u64,
+        measurement: TemperatureAndRelativeHumidity<T>,
+    ) -> Result<MouldIndex, &'static str> {
+        if let Some((last_timestamp, last_measurement)) = self.last_measurement {
+            if timestamp < last_timestamp {
+                return Err("Measurements must be ordered by timestamp");
+            }
+            self.step(
+                &last_measurement,
+                (timestamp - last_timestamp) as f32 / 3_600.,
+            );
+        }
+        self.last_measurement = Some((timestamp, measurement));
+        Ok(self.index())
+    }
+
+    /// Update the model with a series of measurements, ordered by timestamp (in seconds).
+    ///
+    /// Returns an error if the measurements are not ordered by timestamp.
+    pub fn update_with_series(
+        &mut self,
+        measurements: &[(u64, TemperatureAndRelativeHumidity<T>)],
+    ) -> Result<MouldIndex, &'static str> {
+        for (timestamp, measurement) in measurements {
+            self.update(*timestamp, *measurement)?;
+        }
+        Ok(self.index())
+    }
+
+    /// Integrates the mould index over the given duration (in hours) with constant conditions.
+    ///
+    /// With constant conditions, the equations of the model are solved in closed form, so the
+    /// cost does not depend on the duration.
+    fn step(&mut self, measurement: &TemperatureAndRelativeHumidity<T>, hours: f32) {
+        let temperature = measurement.temperature.celsius().value();
+        let relative_humidity = measurement.relative_humidity.value();
+        let critical_relative_humidity = self.critical_relative_humidity(temperature);
+        if temperature > 0. && relative_humidity >= critical_relative_humidity {
+            self.unfavourable_hours = 0.;
+            let (k1_initial, k1_visible) = self.sensitivity.growth_coefficients();
+            let (a, b, c) = self.sensitivity.maximum_index_coefficients();
+            let x = (critical_relative_humidity - relative_humidity)
+                / (critical_relative_humidity - 100.);
+            let maximum_index = a + b * x - c * x * x;
+            // Time needed for the mould index to reach 1 on pine sapwood (in weeks).
+            let weeks = (-0.68 * temperature.ln() - 13.9 * relative_humidity.ln() + 66.02).exp();
+            let rate = 1. / (7. * weeks * 24.);
+            let mut hours = hours;
+            if self.index < 1. && maximum_index > 1. {
+                // The growth intensity changes when the mould index reaches 1.
+                let hours_to_visible = (logit(1., maximum_index)
+                    - logit(self.index, maximum_index))
+                    / (SLOWDOWN * k1_initial * rate);
+                if hours < hours_to_visible {
+                    self.index = grow(self.index, maximum_index, k1_initial * rate, hours);
+                    return;
+                }
+                self.index = 1.;
+                hours -= hours_to_visible;
+            }
+            let k1 = if self.index < 1. {
+                k1_initial
+            } else {
+                k1_visible
+            };
+            self.index = grow(self.index, maximum_index, k1 * rate, hours);
+        } else {
+            // The decline is fast during the first 6 hours, then stops until 24 hours, and
+            // continues slowly after 24 hours.
+            let start = self.unfavourable_hours;
+            let end = start + hours;
+            let decline = 0.001_33 * (end.min(6.) - start).max(0.)
+                + 0.000_667 * (end - start.max(24.)).max(0.);
+            self.index = (self.index - self.sensitivity.decline_coefficient() * decline).max(0.);
+            self.unfavourable_hours = end;
+        }
+    }
+
+    /// Computes the critical relative humidity for mould growth at the given temperature
+    /// (in °C).
+    fn critical_relative_humidity(&self, temperature: f32) -> f32 {
+        let minimum = self.sensitivity.minimum_relative_humidity();
+        if temperature <= 20. {
+            (-0.002_67 * temperature.powi(3) + 0.16 * temperature.powi(2) - 3.13 * temperature
+                + 100.)
+                .max(minimum)
+        } else {
+            minimum
+        }
+    }
+}
+
+/// Computes the mould index reached after the given duration (in hours) from the given mould
+/// index, growing at the given rate (per hour) towards the maximum mould index.
+///
+/// The growth dM/dt = rate · (1 - exp(2.3 · (M - Mmax))) is a logistic equation for
+/// exp(2.3 · (M - Mmax)), solved in closed form.
+fn grow(index: f32, maximum_index: f32, rate: f32, hours: f32) -> f32 {
+    if index >= maximum_index {
+        return index;
+    }
+    let logit = logit(index, maximum_index) + SLOWDOWN * rate * hours;
+    maximum_index - (1. + (-logit).exp()).ln() / SLOWDOWN
+}
+
+/// Computes the logit of exp(2.3 · (M - Mmax)), for a mould index below the maximum one.
+fn logit(index: f32, maximum_index: f32) -> f32 {
+    let exponent = SLOWDOWN * (index - maximum_index);
+    exponent - (1. - exponent.exp()).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Celsius, Fahrenheit, RelativeHumidity};
+
+    #[rstest]
+    #[case(
+        MouldSensitivity::VerySensitive,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(97.).unwrap() },
+        28,
+        4.36,
+        MouldGrowthLevel::VisibleMediumCoverage
+    )]
+    #[case(
+        MouldSensitivity::Sensitive,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(97.).unwrap() },
+        28,
+        1.37,
+        MouldGrowthLevel::InitialMicroscopic
+    )]
+    #[case(
+        MouldSensitivity::MediumResistant,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(97.).unwrap() },
+        28,
+        0.19,
+        MouldGrowthLevel::NoGrowth
+    )]
+    #[case(
+        MouldSensitivity::Resistant,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(97.).unwrap() },
+        28,
+        0.09,
+        MouldGrowthLevel::NoGrowth
+    )]
+    #[case(
+        MouldSensitivity::VerySensitive,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(5.), relative_humidity: RelativeHumidity::new(95.).unwrap() },
+        56,
+        2.14,
+        MouldGrowthLevel::MicroscopicColonies
+    )]
+    #[case(
+        MouldSensitivity::VerySensitive,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(90.).unwrap() },
+        28,
+        0.95,
+        MouldGrowthLevel::NoGrowth
+    )]
+    #[case(
+        MouldSensitivity::VerySensitive,
+        TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(79.).unwrap() },
+        28,
+        0.,
+        MouldGrowthLevel::NoGrowth
+    )]
+    #[case(
+        MouldSensitivity::VerySensitive,
+        TemperatureAndRelativeHumidity{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(97.).unwrap() },
+        28,
+        4.36,
+        MouldGrowthLevel::VisibleMediumCoverage
+    )]
+    fn test_mould_growth_with_constant_conditions<T: Temperature>(
+        #[case] sensitivity: MouldSensitivity,
+        #[case] measurement: TemperatureAndRelativeHumidity<T>,
+        #[case] days: u64,
+        #[case] expected_index: f32,
+        #[case] expected_level: MouldGrowthLevel,
+    ) {
+        let mut model = MouldGrowth::new(sensitivity);
+        for hour in 0..=days * 24 {
+            model.update(hour * 3_600, measurement).unwrap();
+        }
+        assert_relative_eq!(model.index().value(), expected_index, epsilon = 0.01);
+        assert_eq!(model.index().level(), expected_level);
+    }
+
+    #[rstest]
+    fn test_mould_growth_with_series() {
+        let mut model = MouldGrowth::new(MouldSensitivity::VerySensitive);
+        // Daily measurements give the same result as hourly ones, as the model integrates
+        // constant conditions exactly.
+        let humid = TemperatureAndRelativeHumidity {
+            temperature: Celsius(20.),
+            relative_humidity: RelativeHumidity::new(97.).unwrap(),
+        };
+        let series = [(0, humid), (14 * 86_400, humid), (28 * 86_400, humid)];
+        assert_relative_eq!(
+            model.update_with_series(&series).unwrap().value(),
+            4.36,
+            epsilon = 0.01
+        );
+        // A dry week makes the mould index decline.
+        let dry = TemperatureAndRelativeHumidity {
+            temperature: Celsius(20.),
+            relative_humidity: RelativeHumidity::new(50.).unwrap(),
+        };
+        let series = [(28 * 86_400, dry), (35 * 86_400, dry)];
+        assert_relative_eq!(
+            model.update_with_series(&series).unwrap().value(),
+            4.26,
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    fn test_mould_growth_with_huge_gap() {
+        let mut model = MouldGrowth::new(MouldSensitivity::VerySensitive);
+        let humid = TemperatureAndRelativeHumidity {
+            temperature: Celsius(20.),
+            relative_humidity: RelativeHumidity::new(97.).unwrap(),
+        };
+        // The mould index reaches its maximum, without integrating hour by hour.
+        let series = [(0, humid), (u64::MAX, humid)];
+        assert_relative_eq!(
+            model.update_with_series(&series).unwrap().value(),
+            5.5,
+            epsilon = 0.01
+        );
+        let dry = TemperatureAndRelativeHumidity {
+            temperature: Celsius(20.),
+            relative_humidity: RelativeHumidity::new(50.).unwrap(),
+        };
+        let mut model = MouldGrowth::new(MouldSensitivity::VerySensitive);
+        let series = [(0, humid), (28 * 86_400, dry), (u64::MAX, dry)];
+        assert_eq!(model.update_with_series(&series).unwrap().value(), 0.);
+    }
+
+    #[rstest]
+    fn test_mould_growth_with_unordered_timestamps() {
+        let mut model = MouldGrowth::new(MouldSensitivity::Sensitive);
+        let humid = TemperatureAndRelativeHumidity {
+            temperature: Celsius(20.),
+            relative_humidity: RelativeHumidity::new(97.).unwrap(),
+        };
+        let series = [(3_600, humid), (0, humid)];
+        assert_eq!(
+            model.update_with_series(&series),
+            Err("Measurements must be ordered by timestamp")
+        );
+    }
+}
@@ -0,0 +1,185 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+/// The half width of the mixed phase band of the precipitation, on the logistic scale.
+///
+/// With this width, the probability of pure rain (or of pure snow) is 30% when the probability
+/// of liquid precipitation of Koistinen and Saltikoff (1998) is 50%.
+const MIXED_PHASE_HALF_WIDTH: f32 = 0.847;
+
+/// The type of precipitation at the surface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrecipitationType {
+    /// Liquid precipitation.
+    Rain,
+    /// Solid precipitation.
+    Snow,
+    /// A mix of rain and snow (or of rain and partially melted snow).
+    Sleet,
+    /// Supercooled liquid precipitation, freezing on contact with the ground.
+    FreezingRain,
+}
+
+/// The probabilities of the types of precipitation at the surface.
+///
+/// The probability of liquid precipitation is given by the logistic model of Koistinen and
+/// Saltikoff (1998), from the temperature and the relative humidity: dry air cools the falling
+/// snowflakes down by evaporation, so snow falls at higher temperatures in dry air. The mixed
+/// phase (sleet) covers the transition between snow and rain.
+///
+/// The liquid precipitation is freezing rain when the wet-bulb temperature is at or below 0°C:
+/// the surfaces wetted by the rain cool down to the wet-bulb temperature, and the rain freezes
+/// on them. Freezing rain usually comes from a warm layer aloft, which surface observations
+/// cannot see, so its probability is underestimated.
+///
+/// The probabilities are meaningful only when precipitation occurs, they always sum up to 1.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PrecipitationTypeProbabilities {
+    rain: f32,
+    snow: f32,
+    sleet: f32,
+    freezing_rain: f32,
+}
+
+impl PrecipitationTypeProbabilities {
+    /// Create a PrecipitationTypeProbabilities from the temperature, the wet-bulb temperature
+    /// (both in °C) and the relative humidity (in %).
+    pub(crate) fn new(temperature: f32, wet_bulb: f32, relative_humidity: f32) -> Self {
+        let index = 22. - 2.7 * temperature - 0.2 * relative_humidity;
+        let liquid = logistic(-index - MIXED_PHASE_HALF_WIDTH);
+        let snow = 1. - logistic(-index + MIXED_PHASE_HALF_WIDTH);
+        let (rain, freezing_rain) = if wet_bulb <= 0. {
+            (0., liquid)
+        } else {
+            (liquid, 0.)
+        };
+        Self {
+            rain,
+            snow,
+            sleet: 1. - liquid - snow,
+            freezing_rain,
+        }
+    }
+
+    /// Get the probability of rain.
+    pub fn rain(&self) -> f32 {
+        self.rain
+    }
+
+    /// Get the probability of snow.
+    pub fn snow(&self) -> f32 {
+        self.snow
+    }
+
+    /// Get the probability of sleet.
+    pub fn sleet(&self) -> f32 {
+        self.sleet
+    }
+
+    /// Get the probability of freezing rain.
+    pub fn freezing_rain(&self) -> f32 {
+        self.freezing_rain
+    }
+
+    /// Get the probability of the given type of precipitation.
+    pub fn probability(&self, precipitation_type: PrecipitationType) -> f32 {
+        match precipitation_type {
+            PrecipitationType::Rain => self.rain,
+            PrecipitationType::Snow => self.snow,
+            PrecipitationType::Sleet => self.sleet,
+            PrecipitationType::FreezingRain => self.freezing_rain,
+        }
+    }
+
+    /// Get the most likely type of precipitation.
+    pub fn most_likely(&self) -> PrecipitationType {
+        [
+            PrecipitationType::Snow,
+            PrecipitationType::Sleet,
+            PrecipitationType::Rain,
+            PrecipitationType::FreezingRain,
+        ]
+        .into_iter()
+        .fold(
+            PrecipitationType::Snow,
+            |most_likely, precipitation_type| {
+                if self.probability(precipitation_type) > self.probability(most_likely) {
+                    precipitation_type
+                } else {
+                    most_likely
+                }
+            },
+        )
+    }
+}
+
+/// Computes the logistic function.
+fn logistic(value: f32) -> f32 {
+    1. / (1. + (-value).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        BarometricPressure, Celsius, Fahrenheit, RelativeHumidity, Temperature,
+        TemperatureRelativeHumidityAndBarometricPressure,
+    };
+
+    #[rstest]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(5.), relative_humidity: RelativeHumidity::new(80.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0.999, 0., 0.001, 0.), PrecipitationType::Rain)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(1.), relative_humidity: RelativeHumidity::new(100.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0.463, 0.176, 0.361, 0.), PrecipitationType::Rain)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(1.5), relative_humidity: RelativeHumidity::new(90.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0.311, 0.29, 0.4, 0.), PrecipitationType::Sleet)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(0.5), relative_humidity: RelativeHumidity::new(100.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0.183, 0.451, 0.366, 0.), PrecipitationType::Snow)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(2.), relative_humidity: RelativeHumidity::new(50.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0., 0.997, 0.003, 0.001), PrecipitationType::Snow)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-0.5), relative_humidity: RelativeHumidity::new(100.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0., 0.924, 0.061, 0.015), PrecipitationType::Snow)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Celsius(-3.), relative_humidity: RelativeHumidity::new(90.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0., 1., 0., 0.), PrecipitationType::Snow)]
+    #[case(TemperatureRelativeHumidityAndBarometricPressure{ temperature: Fahrenheit(34.7), relative_humidity: RelativeHumidity::new(90.).unwrap(), barometric_pressure: BarometricPressure(1_013.25) }, (0.311, 0.29, 0.4, 0.), PrecipitationType::Sleet)]
+    fn test_precipitation_type_computation<T: Temperature>(
+        #[case] input: TemperatureRelativeHumidityAndBarometricPressure<T>,
+        #[case] expected_probabilities: (f32, f32, f32, f32),
+        #[case] expected_most_likely: PrecipitationType,
+    ) {
+        let probabilities = input.precipitation_type();
+        assert_relative_eq!(
+            probabilities.rain(),
+            expected_probabilities.0,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            probabilities.snow(),
+            expected_probabilities.1,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            probabilities.sleet(),
+            expected_probabilities.2,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            probabilities.freezing_rain(),
+            expected_probabilities.3,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            [
+                PrecipitationType::Rain,
+                PrecipitationType::Snow,
+                PrecipitationType::Sleet,
+                PrecipitationType::FreezingRain,
+            ]
+            .into_iter()
+            .map(|precipitation_type| probabilities.probability(precipitation_type))
+            .sum::<f32>(),
+            1.,
+            epsilon = 0.000_1
+        );
+        assert_eq!(probabilities.most_likely(), expected_most_likely);
+    }
+}
@@ -6,17 +6,88 @@ extern crate std;
 
 use approx::relative_eq;
 
-use crate::{Celsius, Fahrenheit, Temperature};
+use crate::{atmosphere, Celsius, Fahrenheit, Temperature};
+
+/// The lapse rate of the standard atmosphere (in K/m).
+const STANDARD_LAPSE_RATE: f32 = 0.0065;
+/// The sea level pressure of the standard atmosphere (in hPa).
+const STANDARD_SEA_LEVEL_PRESSURE: f32 = 1_013.25;
+/// The sea level temperature of the standard atmosphere (in K).
+const STANDARD_SEA_LEVEL_TEMPERATURE: f32 = 288.15;
+/// The exponent of the barometric formula of the standard atmosphere.
+const STANDARD_PRESSURE_EXPONENT: f32 = 5.255_88;
+/// The lowest pressure of the saturation curve of water, at its triple point (in hPa).
+const TRIPLE_POINT_PRESSURE: f32 = 6.112;
+/// The highest pressure of the saturation curve of water, at its critical point (in hPa).
+const CRITICAL_POINT_PRESSURE: f32 = 220_640.;
+/// The highest temperature of the saturation curve of water, at its critical point (in °C).
+const CRITICAL_POINT_TEMPERATURE: f32 = 373.946;
+/// The coefficients of the saturation curve of water of IAPWS-IF97.
+const SATURATION_COEFFICIENTS: [f32; 10] = [
+    1_167.052_1,
+    -724_213.2,
+    -17.073_847,
+    12_020.825,
+    -3_232_555.,
+    14.915_109,
+    -4_823.266,
+    405_113.4,
+    -0.238_555_58,
+    650.175_35,
+];
 
 /// The barometric pressure type (in hPa).
 #[derive(Clone, Copy, Debug, Default)]
 pub struct BarometricPressure(pub f32);
 
 impl BarometricPressure {
+    /// Create a BarometricPressure from the boiling point of water, the pressure at which
+    /// water boils at the given temperature.
+    ///
+    /// It uses the saturation curve of water of the IAPWS Industrial Formulation 1997 (IF97).
+    /// Returns an error if the temperature is below 0°C or above the critical point of water
+    /// (373.946°C).
+    pub fn from_boiling_point<T: Temperature>(boiling_point: T) -> Result<Self, &'static str> {
+        let temperature = boiling_point.celsius().value();
+        if !(0.0..=CRITICAL_POINT_TEMPERATURE).contains(&temperature) {
+            return Err("Boiling point must be between 0 and 373.946 °C");
+        }
+        let [n1, n2, n3, n4, n5, n6, n7, n8, n9, n10] = SATURATION_COEFFICIENTS;
+        let temperature = temperature + 273.15;
+        let theta = temperature + n9 / (temperature - n10);
+        let a = theta.powi(2) + n1 * theta + n2;
+        let b = n3 * theta.powi(2) + n4 * theta + n5;
+        let c = n6 * theta.powi(2) + n7 * theta + n8;
+        // The saturation pressure is in MPa.
+        Ok(Self(
+            10_000. * (2. * c / (-b + (b.powi(2) - 4. * a * c).sqrt())).powi(4),
+        ))
+    }
+
     /// Get the value of the barometric pressure.
     pub fn value(&self) -> f32 {
         self.0
     }
+
+    /// Computes the boiling point of water at this pressure.
+    ///
+    /// It uses the saturation curve of water of the IAPWS Industrial Formulation 1997 (IF97).
+    /// Returns an error if the pressure is below the triple point (6.112 hPa) or above the
+    /// critical point of water (220640 hPa).
+    pub fn boiling_point<T: Temperature>(&self) -> Result<T, &'static str> {
+        if !(TRIPLE_POINT_PRESSURE..=CRITICAL_POINT_PRESSURE).contains(&self.0) {
+            return Err("Barometric pressure must be between 6.112 and 220640 hPa");
+        }
+        let [n1, n2, n3, n4, n5, n6, n7, n8, n9, n10] = SATURATION_COEFFICIENTS;
+        // The pressure is in MPa.
+        let beta = (self.0 / 10_000.).powf(0.25);
+        let e = beta.powi(2) + n3 * beta + n6;
+        let f = n1 * beta.powi(2) + n4 * beta + n7;
+        let g = n2 * beta.powi(2) + n5 * beta + n8;
+        let d = 2. * g / (-f - (f.powi(2) - 4. * e * g).sqrt());
+        let temperature = (n10 + d - ((n10 + d).powi(2) - 4. * (n9 + n10 * d)).sqrt()) / 2.;
+        Ok(T::from_celsius(Celsius(temperature - 273.15)))
+    }
 }
 
 impl From<f32> for BarometricPressure {
@@ -40,6 +111,27 @@ impl Altitude {
     pub fn value(&self) -> f32 {
         self.0
     }
+
+    /// Computes the barometric pressure at this altitude in the standard atmosphere.
+    ///
+    /// The standard atmosphere (ISA) has a pressure of 1013.25 hPa and a temperature of 15°C
+    /// at sea level, and a lapse rate of 6.5 °C/km. It is valid up to the tropopause (11 km).
+    pub fn standard_barometric_pressure(&self) -> BarometricPressure {
+        BarometricPressure(
+            STANDARD_SEA_LEVEL_PRESSURE
+                * (1. - STANDARD_LAPSE_RATE * self.0 / STANDARD_SEA_LEVEL_TEMPERATURE)
+                    .powf(STANDARD_PRESSURE_EXPONENT),
+        )
+    }
+
+    /// Computes the boiling point of water at this altitude, using the pressure of the
+    /// standard atmosphere.
+    ///
+    /// See [`BarometricPressure::boiling_point()`] and
+    /// [`standard_barometric_pressure()`](Self::standard_barometric_pressure).
+    pub fn boiling_point<T: Temperature>(&self) -> Result<T, &'static str> {
+        self.standard_barometric_pressure().boiling_point()
+    }
 }
 
 impl From<f32> for Altitude {
@@ -72,6 +164,91 @@ impl<T: Temperature> TemperatureAndBarometricPressure<T> {
                 / 0.0065,
         )
     }
+
+    /// Estimates the freezing level, the altitude of the 0°C isotherm, assuming that the
+    /// temperature decreases with the lapse rate of the standard atmosphere (6.5 °C/km).
+    ///
+    /// See [`freezing_level_with_lapse_rate`](Self::freezing_level_with_lapse_rate).
+    pub fn freezing_level(&self) -> Altitude {
+        self.freezing_level_altitude(STANDARD_LAPSE_RATE)
+    }
+
+    /// Estimates the freezing level, the altitude of the 0°C isotherm, assuming that the
+    /// temperature decreases with the given lapse rate (in °C/m) above the station.
+    ///
+    /// The altitude of the station is computed from the barometric pressure. When the
+    /// temperature is at or below 0°C, the freezing level is the altitude of the station.
+    ///
+    /// Returns an error if the lapse rate is not positive.
+    pub fn freezing_level_with_lapse_rate(
+        &self,
+        lapse_rate: f32,
+    ) -> Result<Altitude, &'static str> {
+        if lapse_rate <= 0. {
+            return Err("Lapse rate must be positive");
+        }
+        Ok(self.freezing_level_altitude(lapse_rate))
+    }
+
+    /// Computes the saturated (pseudo-)adiabatic lapse rate (in °C/m, or K/m).
+    ///
+    /// The saturated adiabatic lapse rate is the rate at which a saturated air parcel cools
+    /// while rising, the latent heat released by condensation making it smaller than the dry
+    /// adiabatic lapse rate (9.8 °C/km).
+    pub fn moist_adiabatic_lapse_rate(&self) -> f32 {
+        atmosphere::moist_adiabatic_lapse_rate(
+            self.temperature.celsius().value() + 273.15,
+            self.barometric_pressure.value(),
+        )
+    }
+
+    /// Computes the temperature of a saturated air parcel brought pseudo-adiabatically to the
+    /// given barometric pressure.
+    ///
+    /// The condensed water is assumed to fall out of the parcel immediately.
+    pub fn moist_adiabatic_temperature(&self, barometric_pressure: BarometricPressure) -> T {
+        T::from_celsius(Celsius(
+            atmosphere::pseudo_adiabat(
+                self.temperature.celsius().value() + 273.15,
+                self.barometric_pressure.value(),
+                barometric_pressure.value(),
+            ) - 273.15,
+        ))
+    }
+
+    /// Computes the potential temperature.
+    ///
+    /// The potential temperature is the temperature an air parcel would reach if it was brought
+    /// dry adiabatically to the reference pressure of 1000 hPa.
+    pub fn potential_temperature(&self) -> T {
+        T::from_celsius(Celsius(
+            atmosphere::potential_temperature(
+                self.temperature.celsius().value() + 273.15,
+                self.barometric_pressure.value(),
+            ) - 273.15,
+        ))
+    }
+
+    /// Computes the saturated equivalent potential temperature, using the formula of Bolton
+    /// (1980).
+    ///
+    /// The saturated equivalent potential temperature is the equivalent potential temperature
+    /// the air would have if it was saturated at the same temperature and pressure.
+    pub fn saturated_equivalent_potential_temperature(&self) -> T {
+        T::from_celsius(Celsius(
+            atmosphere::equivalent_potential_temperature(
+                self.temperature.celsius().value() + 273.15,
+                100.,
+                self.barometric_pressure.value(),
+            ) - 273.15,
+        ))
+    }
+
+    /// Computes the altitude of the 0°C isotherm above the station, with the given lapse rate
+    /// (in K/m).
+    fn freezing_level_altitude(&self, lapse_rate: f32) -> Altitude {
+        Altitude(self.altitude().value() + self.temperature.celsius().value().max(0.) / lapse_rate)
+    }
 }
 
 impl<T: Temperature + PartialEq> PartialEq for TemperatureAndBarometricPressure<T> {
@@ -110,6 +287,78 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(BarometricPressure(1_013.25), Ok(Celsius(99.97)))]
+    #[case(BarometricPressure(850.), Ok(Celsius(95.13)))]
+    #[case(BarometricPressure(500.), Ok(Celsius(81.32)))]
+    #[case(BarometricPressure(2_000.), Ok(Celsius(120.21)))]
+    #[case(BarometricPressure(10.), Ok(Celsius(6.97)))]
+    #[case(BarometricPressure(1_013.25), Ok(Fahrenheit(211.95)))]
+    #[case(
+        BarometricPressure(5.),
+        Err::<Celsius, _>("Barometric pressure must be between 6.112 and 220640 hPa")
+    )]
+    #[case(
+        BarometricPressure(250_000.),
+        Err::<Celsius, _>("Barometric pressure must be between 6.112 and 220640 hPa")
+    )]
+    fn test_boiling_point_computation<T: Temperature>(
+        #[case] input: BarometricPressure,
+        #[case] expected_boiling_point: Result<T, &'static str>,
+    ) {
+        match (input.boiling_point::<T>(), expected_boiling_point) {
+            (Ok(value), Ok(expected)) => {
+                assert_relative_eq!(value.value(), expected.value(), epsilon = 0.01)
+            }
+            (value, expected) => assert_eq!(value.err(), expected.err()),
+        }
+    }
+
+    #[rstest]
+    #[case(Altitude(0.), BarometricPressure(1_013.25), Celsius(99.97))]
+    #[case(Altitude(1_000.), BarometricPressure(898.75), Celsius(96.65))]
+    #[case(Altitude(3_000.), BarometricPressure(701.09), Celsius(89.97))]
+    #[case(Altitude(8_848.), BarometricPressure(314.44), Celsius(70.18))]
+    #[case(Altitude(1_000.), BarometricPressure(898.75), Fahrenheit(205.97))]
+    fn test_boiling_point_at_altitude_computation<T: Temperature>(
+        #[case] input: Altitude,
+        #[case] expected_barometric_pressure: BarometricPressure,
+        #[case] expected_boiling_point: T,
+    ) {
+        assert_eq!(
+            input.standard_barometric_pressure(),
+            expected_barometric_pressure
+        );
+        assert_relative_eq!(
+            input.boiling_point::<T>().unwrap().value(),
+            expected_boiling_point.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(Celsius(100.), Ok(BarometricPressure(1_014.18)))]
+    #[case(Celsius(90.), Ok(BarometricPressure(701.82)))]
+    #[case(Celsius(20.), Ok(BarometricPressure(23.39)))]
+    #[case(Celsius(0.), Ok(BarometricPressure(6.11)))]
+    #[case(Fahrenheit(212.), Ok(BarometricPressure(1_014.18)))]
+    #[case(Celsius(-5.), Err("Boiling point must be between 0 and 373.946 °C"))]
+    #[case(Celsius(400.), Err("Boiling point must be between 0 and 373.946 °C"))]
+    fn test_pressure_from_boiling_point_computation<T: Temperature>(
+        #[case] input: T,
+        #[case] expected_barometric_pressure: Result<BarometricPressure, &'static str>,
+    ) {
+        match (
+            BarometricPressure::from_boiling_point(input),
+            expected_barometric_pressure,
+        ) {
+            (Ok(value), Ok(expected)) => {
+                assert_relative_eq!(value.value(), expected.value(), epsilon = 0.05)
+            }
+            (value, expected) => assert_eq!(value, expected),
+        }
+    }
+
     #[rstest]
     #[case(TemperatureAndBarometricPressure{ temperature: Celsius(20.55), barometric_pressure: BarometricPressure(991.32) }, Altitude(188.46))]
     #[case(TemperatureAndBarometricPressure{ temperature: Celsius(17.93), barometric_pressure: 1013.25.into() }, 0.0.into())]
@@ -128,6 +377,95 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(20.), barometric_pressure: BarometricPressure(1_013.25) }, Celsius(18.9), Celsius(60.58))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(30.), barometric_pressure: BarometricPressure(1_000.) }, Celsius(30.), Celsius(113.14))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(-10.), barometric_pressure: BarometricPressure(850.) }, Celsius(2.51), Celsius(8.53))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Fahrenheit(68.), barometric_pressure: BarometricPressure(1_013.25) }, Fahrenheit(66.02), Fahrenheit(141.04))]
+    fn test_potential_temperature_computation<T: Temperature>(
+        #[case] input: TemperatureAndBarometricPressure<T>,
+        #[case] expected_potential_temperature: T,
+        #[case] expected_saturated_equivalent_potential_temperature: T,
+    ) {
+        assert_relative_eq!(
+            input.potential_temperature().value(),
+            expected_potential_temperature.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input.saturated_equivalent_potential_temperature().value(),
+            expected_saturated_equivalent_potential_temperature.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(10.), barometric_pressure: BarometricPressure(1_013.25) }, 0.005, Altitude(1_538.46), Ok(Altitude(2_000.)))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(5.), barometric_pressure: BarometricPressure(900.) }, 0.005, Altitude(1_744.98), Ok(Altitude(1_975.74)))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(15.), barometric_pressure: BarometricPressure(850.) }, 0.005, Altitude(3_814.2), Ok(Altitude(4_506.51)))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(-3.), barometric_pressure: BarometricPressure(950.) }, 0.005, Altitude(512.72), Ok(Altitude(512.72)))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Fahrenheit(50.), barometric_pressure: BarometricPressure(1_013.25) }, 0.005, Altitude(1_538.46), Ok(Altitude(2_000.)))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(10.), barometric_pressure: BarometricPressure(1_013.25) }, 0., Altitude(1_538.46), Err("Lapse rate must be positive"))]
+    fn test_freezing_level_computation<T: Temperature>(
+        #[case] input: TemperatureAndBarometricPressure<T>,
+        #[case] lapse_rate: f32,
+        #[case] expected_freezing_level: Altitude,
+        #[case] expected_freezing_level_with_lapse_rate: Result<Altitude, &'static str>,
+    ) {
+        assert_relative_eq!(
+            input.freezing_level().value(),
+            expected_freezing_level.value(),
+            epsilon = 0.1
+        );
+        match (
+            input.freezing_level_with_lapse_rate(lapse_rate),
+            expected_freezing_level_with_lapse_rate,
+        ) {
+            (Ok(value), Ok(expected)) => {
+                assert_relative_eq!(value.value(), expected.value(), epsilon = 0.1)
+            }
+            (value, expected) => assert_eq!(value, expected),
+        }
+    }
+
+    #[rstest]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(20.), barometric_pressure: BarometricPressure(1_000.) }, 0.004_22)]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(0.), barometric_pressure: BarometricPressure(1_000.) }, 0.006_47)]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(-20.), barometric_pressure: BarometricPressure(500.) }, 0.007_73)]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(-40.), barometric_pressure: BarometricPressure(300.) }, 0.009_02)]
+    #[case(TemperatureAndBarometricPressure{ temperature: Fahrenheit(68.), barometric_pressure: BarometricPressure(1_000.) }, 0.004_22)]
+    fn test_moist_adiabatic_lapse_rate_computation<T: Temperature>(
+        #[case] input: TemperatureAndBarometricPressure<T>,
+        #[case] expected_lapse_rate: f32,
+    ) {
+        assert_relative_eq!(
+            input.moist_adiabatic_lapse_rate(),
+            expected_lapse_rate,
+            epsilon = 0.000_01
+        );
+    }
+
+    #[rstest]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(20.), barometric_pressure: BarometricPressure(1_000.) }, BarometricPressure(500.), Celsius(-8.45))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(20.), barometric_pressure: BarometricPressure(1_000.) }, BarometricPressure(300.), Celsius(-36.6))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(0.), barometric_pressure: BarometricPressure(850.) }, BarometricPressure(500.), Celsius(-29.77))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(15.), barometric_pressure: BarometricPressure(700.) }, BarometricPressure(900.), Celsius(23.41))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Celsius(25.), barometric_pressure: BarometricPressure(1_000.) }, BarometricPressure(1_000.), Celsius(25.))]
+    #[case(TemperatureAndBarometricPressure{ temperature: Fahrenheit(68.), barometric_pressure: BarometricPressure(1_000.) }, BarometricPressure(500.), Fahrenheit(16.79))]
+    fn test_moist_adiabatic_temperature_computation<T: Temperature>(
+        #[case] input: TemperatureAndBarometricPressure<T>,
+        #[case] barometric_pressure: BarometricPressure,
+        #[case] expected_temperature: T,
+    ) {
+        assert_relative_eq!(
+            input
+                .moist_adiabatic_temperature(barometric_pressure)
+                .value(),
+            expected_temperature.value(),
+            epsilon = 0.02
+        );
+    }
+
     #[rstest]
     #[case(
         TemperatureAndBarometricPressure{ temperature: Celsius(21.18), barometric_pressure: BarometricPressure(991.32) },
@@ -0,0 +1,760 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use crate::{
+    BarometricPressure, Celsius, RelativeHumidity, Temperature, TemperatureAndRelativeHumidity,
+};
+
+/// The ratio of the molecular masses of water vapour and dry air.
+const MOLECULAR_MASS_RATIO: f32 = 0.621_945;
+/// The tolerance on the degree of saturation above which the air is supersaturated, to accept
+/// saturated air despite the rounding errors of the enthalpy and of the humidity ratio.
+const SATURATION_TOLERANCE: f32 = 1e-4;
+/// The specific enthalpy of saturated steam at 100°C (in kJ/kg).
+const STEAM_ENTHALPY: f32 = 2_676.;
+/// The number of iterations used to solve the implicit equations.
+const ITERATIONS: usize = 40;
+
+/// The psychrometric state of moist air.
+///
+/// The psychrometric state gives all the thermodynamic properties of moist air at a given
+/// barometric pressure: humidity ratio, relative humidity, dew point, wet bulb temperature,
+/// specific enthalpy, specific volume and degree of saturation. It follows the equations of the
+/// ASHRAE Handbook - Fundamentals (2017), with the saturation pressure of Hyland and Wexler over
+/// liquid water above 0°C and over ice below 0°C.
+/// The state can be solved from any two independent properties plus the barometric pressure.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PsychrometricState<T: Temperature> {
+    dry_bulb: T,
+    humidity_ratio: f32,
+    barometric_pressure: BarometricPressure,
+}
+
+impl<T: Temperature> PsychrometricState<T> {
+    /// Create a PsychrometricState from the dry bulb temperature and the relative humidity.
+    pub fn from_relative_humidity(
+        dry_bulb: T,
+        relative_humidity: RelativeHumidity,
+        barometric_pressure: BarometricPressure,
+    ) -> Self {
+        let pressure = kilopascals(barometric_pressure);
+        let vapour_pressure = relative_humidity.value() / 100.
+            * saturation_vapour_pressure(dry_bulb.celsius().value());
+        Self {
+            dry_bulb,
+            humidity_ratio: humidity_ratio(vapour_pressure, pressure),
+            barometric_pressure,
+        }
+    }
+
+    /// Create a PsychrometricState from the dry bulb and the wet bulb temperatures.
+    ///
+    /// Returns an error if the wet bulb temperature is above the dry bulb temperature, or if
+    /// they do not describe a possible state of moist air.
+    pub fn from_wet_bulb(
+        dry_bulb: T,
+        wet_bulb: T,
+        barometric_pressure: BarometricPressure,
+    ) -> Result<Self, &'static str> {
+        let dry_bulb_celsius = dry_bulb.celsius().value();
+        let wet_bulb = wet_bulb.celsius().value();
+        if wet_bulb > dry_bulb_celsius {
+            return Err("Wet bulb temperature must not be above dry bulb temperature");
+        }
+        let humidity_ratio = humidity_ratio_from_wet_bulb(
+            dry_bulb_celsius,
+            wet_bulb,
+            kilopascals(barometric_pressure),
+        );
+        if humidity_ratio < 0. {
+            return Err("Wet bulb temperature is too low for the dry bulb temperature");
+        }
+        Ok(Self {
+            dry_bulb,
+            humidity_ratio,
+            barometric_pressure,
+        })
+    }
+
+    /// Create a PsychrometricState from the dry bulb temperature and the dew point.
+    ///
+    /// Returns an error if the dew point is above the dry bulb temperature.
+    pub fn from_dew_point(
+        dry_bulb: T,
+        dew_point: T,
+        barometric_pressure: BarometricPressure,
+    ) -> Result<Self, &'static str> {
+        let dew_point = dew_point.celsius().value();
+        if dew_point > dry_bulb.celsius().value() {
+            return Err("Dew point must not be above dry bulb temperature");
+        }
+        Ok(Self {
+            dry_bulb,
+            humidity_ratio: humidity_ratio(
+                saturation_vapour_pressure(dew_point),
+                kilopascals(barometric_pressure),
+            ),
+            barometric_pressure,
+        })
+    }
+
+    /// Create a PsychrometricState from the specific enthalpy (in kJ/kg of dry air) and the
+    /// humidity ratio (in kg of water vapour per kg of dry air).
+    ///
+    /// Returns an error if the humidity ratio is negative, or if the air would be
+    /// supersaturated.
+    pub fn from_enthalpy_and_humidity_ratio(
+        enthalpy: f32,
+        humidity_ratio: f32,
+        barometric_pressure: BarometricPressure,
+    ) -> Result<Self, &'static str> {
+        if humidity_ratio < 0. {
+            return Err("Humidity ratio must not be negative");
+        }
+        let state = Self {
+            dry_bulb: T::from_celsius(Celsius(
+                (enthalpy - 2_501. * humidity_ratio) / (1.006 + 1.86 * humidity_ratio),
+            )),
+            humidity_ratio,
+            barometric_pressure,
+        };
+        if state.degree_of_saturation() > 1. + SATURATION_TOLERANCE {
+            return Err("Air cannot be supersaturated");
+        }
+        Ok(state)
+    }
+
+    /// Get the dry bulb temperature.
+    pub fn dry_bulb(&self) -> T {
+        self.dry_bulb
+    }
+
+    /// Get the barometric pressure (in hPa).
+    pub fn barometric_pressure(&self) -> BarometricPressure {
+        self.barometric_pressure
+    }
+
+    /// Get the humidity ratio (in kg of water vapour per kg of dry air).
+    pub fn humidity_ratio(&self) -> f32 {
+        self.humidity_ratio
+    }
+
+    /// Computes the partial pressure of water vapour (in hPa).
+    pub fn vapour_pressure(&self) -> f32 {
+        self.vapour_pressure_kilopascals() * 10.
+    }
+
+    /// Computes the relative humidity.
+    pub fn relative_humidity(&self) -> RelativeHumidity {
+        RelativeHumidity::from_valid(
+            (100. * self.vapour_pressure_kilopascals()
+                / saturation_vapour_pressure(self.dry_bulb.celsius().value()))
+            .clamp(0., 100.),
+        )
+    }
+
+    /// Computes the dew point temperature.
+    ///
+    /// Below 0°C, the dew point is computed over ice (frost point).
+    pub fn dew_point(&self) -> T {
+        let vapour_pressure = self.vapour_pressure_kilopascals();
+        T::from_celsius(Celsius(solve(-100., 200., |temperature| {
+            saturation_vapour_pressure(temperature) - vapour_pressure
+        })))
+    }
+
+    /// Computes the thermodynamic wet bulb temperature.
+    pub fn wet_bulb(&self) -> T {
+        let dry_bulb = self.dry_bulb.celsius().value();
+        let pressure = kilopascals(self.barometric_pressure);
+        T::from_celsius(Celsius(solve(
+            self.dew_point().celsius().value(),
+            dry_bulb,
+            |wet_bulb| {
+                humidity_ratio_from_wet_bulb(dry_bulb, wet_bulb, pressure) - self.humidity_ratio
+            },
+        )))
+    }
+
+    /// Computes the specific enthalpy (in kJ/kg of dry air).
+    pub fn enthalpy(&self) -> f32 {
+        let dry_bulb = self.dry_bulb.celsius().value();
+        1.006 * dry_bulb + self.humidity_ratio * (2_501. + 1.86 * dry_bulb)
+    }
+
+    /// Computes the specific volume (in m³/kg of dry air).
+    pub fn specific_volume(&self) -> f32 {
+        0.287_042
+            * (self.dry_bulb.celsius().value() + 273.15)
+            * (1. + 1.607_858 * self.humidity_ratio)
+            / kilopascals(self.barometric_pressure)
+    }
+
+    /// Computes the degree of saturation, the ratio between the humidity ratio and the humidity
+    /// ratio of saturated air at the same temperature and pressure.
+    pub fn degree_of_saturation(&self) -> f32 {
+        self.humidity_ratio
+            / humidity_ratio(
+                saturation_vapour_pressure(self.dry_bulb.celsius().value()),
+                kilopascals(self.barometric_pressure),
+            )
+    }
+
+    /// Computes the adiabatic mixing of the air with another airstream.
+    ///
+    /// Both airstreams must be at the same barometric pressure. Returns an error if the mixed
+    /// air would be supersaturated (fog).
+    pub fn mix(
+        &self,
+        air_flow: AirFlow,
+        other: &Self,
+        other_air_flow: AirFlow,
+    ) -> Result<Self, &'static str> {
+        let mass_flow = self.mass_flow(air_flow);
+        let other_mass_flow = other.mass_flow(other_air_flow);
+        let total_mass_flow = mass_flow + other_mass_flow;
+        if total_mass_flow <= 0. {
+            return Err("Air flow must be positive");
+        }
+        Self::from_enthalpy_and_humidity_ratio(
+            (mass_flow * self.enthalpy() + other_mass_flow * other.enthalpy()) / total_mass_flow,
+            (mass_flow * self.humidity_ratio + other_mass_flow * other.humidity_ratio)
+                / total_mass_flow,
+            self.barometric_pressure,
+        )
+    }
+
+    /// Computes the sensible heating or cooling of the air up or down to the given outlet dry
+    /// bulb temperature, at constant humidity ratio.
+    ///
+    /// Returns an error if the outlet temperature is below the dew point of the air.
+    pub fn sensible_heating(
+        &self,
+        outlet_dry_bulb: T,
+        air_flow: AirFlow,
+    ) -> Result<PsychrometricProcess<T>, &'static str> {
+        if outlet_dry_bulb.celsius().value() < self.dew_point().celsius().value() {
+            return Err("Outlet temperature must not be below the dew point");
+        }
+        let outlet = Self {
+            dry_bulb: outlet_dry_bulb,
+            humidity_ratio: self.humidity_ratio,
+            barometric_pressure: self.barometric_pressure,
+        };
+        Ok(PsychrometricProcess::new(self, outlet, air_flow))
+    }
+
+    /// Computes the cooling and dehumidification of the air by a coil with the given apparatus
+    /// dew point and bypass factor (between 0 and 1).
+    ///
+    /// The outlet state lies on the straight line between the inlet state and the saturated
+    /// state at the apparatus dew point, the bypass factor being the fraction of air leaving the
+    /// coil unaffected.
+    /// Returns an error if the bypass factor is not between 0 and 1, or if the apparatus dew
+    /// point is not below the dew point of the air.
+    pub fn cooling_and_dehumidification(
+        &self,
+        apparatus_dew_point: T,
+        bypass_factor: f32,
+        air_flow: AirFlow,
+    ) -> Result<PsychrometricProcess<T>, &'static str> {
+        if !(0.0..=1.0).contains(&bypass_factor) {
+            return Err("Bypass factor must be between 0 and 1");
+        }
+        if apparatus_dew_point.celsius().value() >= self.dew_point().celsius().value() {
+            return Err("Apparatus dew point must be below the dew point of the air");
+        }
+        let apparatus = Self::from_dew_point(
+            apparatus_dew_point,
+            apparatus_dew_point,
+            self.barometric_pressure,
+        )?;
+        let outlet = Self::from_enthalpy_and_humidity_ratio(
+            bypass_factor * self.enthalpy() + (1. - bypass_factor) * apparatus.enthalpy(),
+            bypass_factor * self.humidity_ratio + (1. - bypass_factor) * apparatus.humidity_ratio,
+            self.barometric_pressure,
+        )?;
+        Ok(PsychrometricProcess::new(self, outlet, air_flow))
+    }
+
+    /// Computes the direct evaporative cooling of the air, with the given saturation
+    /// effectiveness (between 0 and 1).
+    ///
+    /// The evaporative cooling is an adiabatic saturation process, at constant wet bulb
+    /// temperature: the effectiveness is the ratio between the actual temperature drop and the
+    /// wet bulb depression.
+    /// Returns an error if the effectiveness is not between 0 and 1.
+    pub fn evaporative_cooling(
+        &self,
+        effectiveness: f32,
+        air_flow: AirFlow,
+    ) -> Result<PsychrometricProcess<T>, &'static str> {
+        if !(0.0..=1.0).contains(&effectiveness) {
+            return Err("Effectiveness must be between 0 and 1");
+        }
+        let dry_bulb = self.dry_bulb.celsius().value();
+        let wet_bulb = self.wet_bulb().celsius().value();
+        let outlet_dry_bulb = dry_bulb - effectiveness * (dry_bulb - wet_bulb);
+        let outlet = Self {
+            dry_bulb: T::from_celsius(Celsius(outlet_dry_bulb)),
+            humidity_ratio: humidity_ratio_from_wet_bulb(
+                outlet_dry_bulb,
+                wet_bulb,
+                kilopascals(self.barometric_pressure),
+            ),
+            barometric_pressure: self.barometric_pressure,
+        };
+        Ok(PsychrometricProcess::new(self, outlet, air_flow))
+    }
+
+    /// Computes the humidification of the air up to the given outlet humidity ratio (in kg of
+    /// water vapour per kg of dry air), by injection of saturated steam at 100°C.
+    ///
+    /// Returns an error if the outlet humidity ratio is below the humidity ratio of the air, or
+    /// if the outlet air would be supersaturated.
+    pub fn steam_humidification(
+        &self,
+        outlet_humidity_ratio: f32,
+        air_flow: AirFlow,
+    ) -> Result<PsychrometricProcess<T>, &'static str> {
+        if outlet_humidity_ratio < self.humidity_ratio {
+            return Err("Outlet humidity ratio must not be below the humidity ratio of the air");
+        }
+        let outlet = Self::from_enthalpy_and_humidity_ratio(
+            self.enthalpy() + (outlet_humidity_ratio - self.humidity_ratio) * STEAM_ENTHALPY,
+            outlet_humidity_ratio,
+            self.barometric_pressure,
+        )?;
+        Ok(PsychrometricProcess::new(self, outlet, air_flow))
+    }
+
+    /// Computes the mass flow of dry air (in kg/s) corresponding to the given air flow.
+    fn mass_flow(&self, air_flow: AirFlow) -> f32 {
+        match air_flow {
+            AirFlow::Mass(value) => value,
+            AirFlow::Volume(value) => value / self.specific_volume(),
+        }
+    }
+
+    /// Computes the partial pressure of water vapour (in kPa).
+    fn vapour_pressure_kilopascals(&self) -> f32 {
+        let pressure = kilopascals(self.barometric_pressure);
+        pressure * self.humidity_ratio / (MOLECULAR_MASS_RATIO + self.humidity_ratio)
+    }
+}
+
+impl<T: Temperature> From<PsychrometricState<T>> for TemperatureAndRelativeHumidity<T> {
+    fn from(value: PsychrometricState<T>) -> Self {
+        Self {
+            temperature: value.dry_bulb(),
+            relative_humidity: value.relative_humidity(),
+        }
+    }
+}
+
+/// The flow of air through a psychrometric process.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AirFlow {
+    /// The mass flow of dry air (in kg/s).
+    Mass(f32),
+    /// The volume flow of moist air at the inlet conditions (in m³/s).
+    Volume(f32),
+}
+
+/// A psychrometric process, giving the outlet state of the air and the loads of the process.
+///
+/// The loads are positive when heat or moisture is added to the air, and negative when it is
+/// removed from the air.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PsychrometricProcess<T: Temperature> {
+    outlet: PsychrometricState<T>,
+    mass_flow: f32,
+    sensible_load: f32,
+    total_load: f32,
+    moisture_flow: f32,
+}
+
+impl<T: Temperature> PsychrometricProcess<T> {
+    /// Create a PsychrometricProcess from the inlet and outlet states.
+    fn new(
+        inlet: &PsychrometricState<T>,
+        outlet: PsychrometricState<T>,
+        air_flow: AirFlow,
+    ) -> Self {
+        let mass_flow = inlet.mass_flow(air_flow);
+        Self {
+            outlet,
+            mass_flow,
+            sensible_load: mass_flow
+                * (1.006 + 1.86 * inlet.humidity_ratio)
+                * (outlet.dry_bulb.celsius().value() - inlet.dry_bulb.celsius().value()),
+            total_load: mass_flow * (outlet.enthalpy() - inlet.enthalpy()),
+            moisture_flow: mass_flow * (outlet.humidity_ratio - inlet.humidity_ratio),
+        }
+    }
+
+    /// Get the outlet state of the air.
+    pub fn outlet(&self) -> PsychrometricState<T> {
+        self.outlet
+    }
+
+    /// Get the mass flow of dry air (in kg/s).
+    pub fn mass_flow(&self) -> f32 {
+        self.mass_flow
+    }
+
+    /// Get the sensible load (in kW).
+    pub fn sensible_load(&self) -> f32 {
+        self.sensible_load
+    }
+
+    /// Get the latent load (in kW).
+    pub fn latent_load(&self) -> f32 {
+        self.total_load - self.sensible_load
+    }
+
+    /// Get the total load (in kW).
+    pub fn total_load(&self) -> f32 {
+        self.total_load
+    }
+
+    /// Get the flow of water added to the air (in kg/s), negative when water condenses.
+    pub fn moisture_flow(&self) -> f32 {
+        self.moisture_flow
+    }
+}
+
+/// Converts a barometric pressure to kPa.
+fn kilopascals(barometric_pressure: BarometricPressure) -> f32 {
+    barometric_pressure.value() / 10.
+}
+
+/// Computes the saturation pressure of water vapour (in kPa) at the given temperature (in °C),
+/// over liquid water above 0°C and over ice below 0°C, using the formulas of Hyland and Wexler
+/// (1983) that the ASHRAE psychrometric relations are based on.
+pub(crate) fn saturation_vapour_pressure(temperature: f32) -> f32 {
+    let t = temperature + 273.15;
+    let ln = if temperature < 0. {
+        -5_674.536 / t + 6.392_524_7 - 9.677_843e-3 * t
+            + 6.221_57e-7 * t.powi(2)
+            + 2.074_782_5e-9 * t.powi(3)
+            - 9.484_024e-13 * t.powi(4)
+            + 4.163_502 * t.ln()
+    } else {
+        -5_800.220_6 / t + 1.391_499_3 - 4.864_024e-2 * t + 4.176_476_8e-5 * t.powi(2)
+            - 1.445_209_3e-8 * t.powi(3)
+            + 6.545_967 * t.ln()
+    };
+    ln.exp() / 1_000.
+}
+
+/// Computes the humidity ratio from the partial pressure of water vapour and the barometric
+/// pressure (both in kPa).
+pub(crate) fn humidity_ratio(vapour_pressure: f32, pressure: f32) -> f32 {
+    MOLECULAR_MASS_RATIO * vapour_pressure / (pressure - vapour_pressure)
+}
+
+/// Computes the humidity ratio from the dry bulb and wet bulb temperatures (in °C) and the
+/// barometric pressure (in kPa).
+fn humidity_ratio_from_wet_bulb(dry_bulb: f32, wet_bulb: f32, pressure: f32) -> f32 {
+    let saturation_humidity_ratio = humidity_ratio(saturation_vapour_pressure(wet_bulb), pressure);
+    if wet_bulb >= 0. {
+        ((2_501. - 2.326 * wet_bulb) * saturation_humidity_ratio - 1.006 * (dry_bulb - wet_bulb))
+            / (2_501. + 1.86 * dry_bulb - 4.186 * wet_bulb)
+    } else {
+        ((2_830. - 0.24 * wet_bulb) * saturation_humidity_ratio - 1.006 * (dry_bulb - wet_bulb))
+            / (2_830. + 1.86 * dry_bulb - 2.1 * wet_bulb)
+    }
+}
+
+/// Finds the root of an increasing function between the given bounds, by bisection.
+pub(crate) fn solve(mut lower: f32, mut upper: f32, function: impl Fn(f32) -> f32) -> f32 {
+    for _ in 0..ITERATIONS {
+        let middle = (lower + upper) / 2.;
+        if function(middle) > 0. {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+    (lower + upper) / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Fahrenheit;
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap() }.psychrometric_state(BarometricPressure(1_013.25)), 0.007_262, 50., Celsius(9.27), Celsius(13.78))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(80.).unwrap() }.psychrometric_state(BarometricPressure(1_013.25)), 0.001_979, 80., Celsius(-7.59), Celsius(-5.88))]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(50.).unwrap() }.psychrometric_state(BarometricPressure(1_013.25)), 0.007_262, 50., Fahrenheit(48.69), Fahrenheit(56.81))]
+    #[case(PsychrometricState::from_wet_bulb(Celsius(30.), Celsius(25.), BarometricPressure(1_013.25)).unwrap(), 0.017_954, 66.95, Celsius(23.19), Celsius(25.))]
+    #[case(PsychrometricState::from_dew_point(Celsius(25.), Celsius(10.), BarometricPressure(900.)).unwrap(), 0.008_603, 38.75, Celsius(10.), Celsius(15.61))]
+    #[case(PsychrometricState::<Celsius>::from_enthalpy_and_humidity_ratio(50., 0.01, BarometricPressure(1_013.25)).unwrap(), 0.01, 52.47, Celsius(14.05), Celsius(17.78))]
+    fn test_psychrometric_state_moisture_computation<T: Temperature>(
+        #[case] state: PsychrometricState<T>,
+        #[case] expected_humidity_ratio: f32,
+        #[case] expected_relative_humidity: f32,
+        #[case] expected_dew_point: T,
+        #[case] expected_wet_bulb: T,
+    ) {
+        assert_relative_eq!(
+            state.humidity_ratio(),
+            expected_humidity_ratio,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            state.relative_humidity().value(),
+            expected_relative_humidity,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            state.dew_point().value(),
+            expected_dew_point.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            state.wet_bulb().value(),
+            expected_wet_bulb.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(20.), relative_humidity: RelativeHumidity::new(50.).unwrap() }.psychrometric_state(BarometricPressure(1_013.25)), Celsius(20.), 38.55, 0.840_2, 0.494)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(-5.), relative_humidity: RelativeHumidity::new(80.).unwrap() }.psychrometric_state(BarometricPressure(1_013.25)), Celsius(-5.), -0.1, 0.762_1, 0.799)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(68.), relative_humidity: RelativeHumidity::new(50.).unwrap() }.psychrometric_state(BarometricPressure(1_013.25)), Fahrenheit(68.), 38.55, 0.840_2, 0.494)]
+    #[case(PsychrometricState::from_wet_bulb(Celsius(30.), Celsius(25.), BarometricPressure(1_013.25)).unwrap(), Celsius(30.), 76.08, 0.883_6, 0.66)]
+    #[case(PsychrometricState::from_dew_point(Celsius(25.), Celsius(10.), BarometricPressure(900.)).unwrap(), Celsius(25.), 47.07, 0.964_1, 0.379)]
+    #[case(PsychrometricState::<Celsius>::from_enthalpy_and_humidity_ratio(50., 0.01, BarometricPressure(1_013.25)).unwrap(), Celsius(24.39), 50., 0.856_4, 0.517)]
+    fn test_psychrometric_state_energy_computation<T: Temperature>(
+        #[case] state: PsychrometricState<T>,
+        #[case] expected_dry_bulb: T,
+        #[case] expected_enthalpy: f32,
+        #[case] expected_specific_volume: f32,
+        #[case] expected_degree_of_saturation: f32,
+    ) {
+        assert_relative_eq!(
+            state.dry_bulb().value(),
+            expected_dry_bulb.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(state.enthalpy(), expected_enthalpy, epsilon = 0.01);
+        assert_relative_eq!(
+            state.specific_volume(),
+            expected_specific_volume,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            state.degree_of_saturation(),
+            expected_degree_of_saturation,
+            epsilon = 0.001
+        );
+    }
+
+    #[rstest]
+    #[case(
+        PsychrometricState::from_wet_bulb(
+            Celsius(20.),
+            Celsius(25.),
+            BarometricPressure(1_013.25)
+        ),
+        "Wet bulb temperature must not be above dry bulb temperature"
+    )]
+    #[case(PsychrometricState::from_wet_bulb(Celsius(40.), Celsius(-10.), BarometricPressure(1_013.25)), "Wet bulb temperature is too low for the dry bulb temperature")]
+    #[case(
+        PsychrometricState::from_dew_point(
+            Celsius(20.),
+            Celsius(21.),
+            BarometricPressure(1_013.25)
+        ),
+        "Dew point must not be above dry bulb temperature"
+    )]
+    #[case(PsychrometricState::from_enthalpy_and_humidity_ratio(50., -0.01, BarometricPressure(1_013.25)), "Humidity ratio must not be negative")]
+    #[case(
+        PsychrometricState::from_enthalpy_and_humidity_ratio(
+            30.,
+            0.02,
+            BarometricPressure(1_013.25)
+        ),
+        "Air cannot be supersaturated"
+    )]
+    fn test_psychrometric_state_errors(
+        #[case] state: Result<PsychrometricState<Celsius>, &'static str>,
+        #[case] expected_error: &'static str,
+    ) {
+        assert_eq!(state.err(), Some(expected_error));
+    }
+
+    #[rstest]
+    #[case(Celsius(30.7))]
+    #[case(Celsius(40.2))]
+    #[case(Fahrenheit(34.))]
+    fn test_psychrometric_state_from_enthalpy_and_humidity_ratio_saturated<T: Temperature>(
+        #[case] dry_bulb: T,
+    ) {
+        let saturated =
+            PsychrometricState::from_wet_bulb(dry_bulb, dry_bulb, BarometricPressure(1_013.25))
+                .unwrap();
+        let state = PsychrometricState::<T>::from_enthalpy_and_humidity_ratio(
+            saturated.enthalpy(),
+            saturated.humidity_ratio(),
+            BarometricPressure(1_013.25),
+        )
+        .unwrap();
+        assert_relative_eq!(state.dry_bulb().value(), dry_bulb.value(), epsilon = 0.01);
+        assert_relative_eq!(state.degree_of_saturation(), 1., epsilon = 0.000_1);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Celsius(21.18), relative_humidity: RelativeHumidity::new(45.59).unwrap() }, BarometricPressure(1_013.25), 0.007_119)]
+    #[case(TemperatureAndRelativeHumidity{ temperature: Fahrenheit(70.12), relative_humidity: RelativeHumidity::new(45.59).unwrap() }, BarometricPressure(1_013.25), 0.007_119)]
+    fn test_psychrometric_state_from_temperature_and_relative_humidity<
+        T: Temperature + PartialEq,
+    >(
+        #[case] input: TemperatureAndRelativeHumidity<T>,
+        #[case] barometric_pressure: BarometricPressure,
+        #[case] expected_humidity_ratio: f32,
+    ) {
+        let state = input.psychrometric_state(barometric_pressure);
+        assert_relative_eq!(
+            state.humidity_ratio(),
+            expected_humidity_ratio,
+            epsilon = 0.0001
+        );
+        let value: TemperatureAndRelativeHumidity<T> = state.into();
+        assert!(value == input);
+    }
+
+    #[rstest]
+    #[case(
+        PsychrometricState::from_relative_humidity(Celsius(30.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)),
+        AirFlow::Mass(1.),
+        PsychrometricState::from_relative_humidity(Celsius(20.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)),
+        AirFlow::Mass(2.),
+        Celsius(23.36),
+        0.009_278
+    )]
+    #[case(
+        PsychrometricState::from_relative_humidity(Celsius(30.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)),
+        AirFlow::Volume(1.),
+        PsychrometricState::from_relative_humidity(Celsius(20.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)),
+        AirFlow::Volume(1.),
+        Celsius(24.92),
+        0.010_221
+    )]
+    #[case(
+        PsychrometricState::from_relative_humidity(Fahrenheit(86.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)),
+        AirFlow::Mass(1.),
+        PsychrometricState::from_relative_humidity(Fahrenheit(68.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)),
+        AirFlow::Mass(2.),
+        Fahrenheit(74.04),
+        0.009_278
+    )]
+    fn test_psychrometric_mixing<T: Temperature>(
+        #[case] input: PsychrometricState<T>,
+        #[case] air_flow: AirFlow,
+        #[case] other: PsychrometricState<T>,
+        #[case] other_air_flow: AirFlow,
+        #[case] expected_dry_bulb: T,
+        #[case] expected_humidity_ratio: f32,
+    ) {
+        let mixed = input.mix(air_flow, &other, other_air_flow).unwrap();
+        assert_relative_eq!(
+            mixed.dry_bulb().value(),
+            expected_dry_bulb.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            mixed.humidity_ratio(),
+            expected_humidity_ratio,
+            epsilon = 0.0001
+        );
+    }
+
+    #[rstest]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(10.), RelativeHumidity::new(80.).unwrap(), BarometricPressure(1_013.25)).sensible_heating(Celsius(30.), AirFlow::Volume(1.)), Celsius(30.), 23.14, 25.12, 0., 0.)]
+    #[case(PsychrometricState::from_relative_humidity(Fahrenheit(50.), RelativeHumidity::new(80.).unwrap(), BarometricPressure(1_013.25)).sensible_heating(Fahrenheit(86.), AirFlow::Volume(1.)), Fahrenheit(86.), 23.14, 25.12, 0., 0.)]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(30.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)).cooling_and_dehumidification(Celsius(10.), 0.1, AirFlow::Mass(1.)), Celsius(12.02), 93.87, -18.53, -12.9, -0.005_112)]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(35.), RelativeHumidity::new(20.).unwrap(), BarometricPressure(1_013.25)).evaporative_cooling(0.8, AirFlow::Mass(1.)), Celsius(22.1), 74.01, -13.15, 13.57, 0.005_338)]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(20.), RelativeHumidity::new(30.).unwrap(), BarometricPressure(1_013.25)).steam_humidification(0.008, AirFlow::Mass(1.)), Celsius(20.49), 53.36, 0.5, 9.3, 0.003_663)]
+    fn test_psychrometric_process<T: Temperature>(
+        #[case] process: Result<PsychrometricProcess<T>, &'static str>,
+        #[case] expected_dry_bulb: T,
+        #[case] expected_relative_humidity: f32,
+        #[case] expected_sensible_load: f32,
+        #[case] expected_latent_load: f32,
+        #[case] expected_moisture_flow: f32,
+    ) {
+        let process = process.unwrap();
+        assert_relative_eq!(
+            process.outlet().dry_bulb().value(),
+            expected_dry_bulb.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            process.outlet().relative_humidity().value(),
+            expected_relative_humidity,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            process.sensible_load(),
+            expected_sensible_load,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(process.latent_load(), expected_latent_load, epsilon = 0.01);
+        assert_relative_eq!(
+            process.total_load(),
+            expected_sensible_load + expected_latent_load,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            process.moisture_flow(),
+            expected_moisture_flow,
+            epsilon = 0.000_01
+        );
+    }
+
+    #[rstest]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(20.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)).sensible_heating(Celsius(5.), AirFlow::Mass(1.)), "Outlet temperature must not be below the dew point")]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(30.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)).cooling_and_dehumidification(Celsius(10.), 1.5, AirFlow::Mass(1.)), "Bypass factor must be between 0 and 1")]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(30.), RelativeHumidity::new(50.).unwrap(), BarometricPressure(1_013.25)).cooling_and_dehumidification(Celsius(20.), 0.1, AirFlow::Mass(1.)), "Apparatus dew point must be below the dew point of the air")]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(35.), RelativeHumidity::new(20.).unwrap(), BarometricPressure(1_013.25)).evaporative_cooling(-0.2, AirFlow::Mass(1.)), "Effectiveness must be between 0 and 1")]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(20.), RelativeHumidity::new(30.).unwrap(), BarometricPressure(1_013.25)).steam_humidification(0.002, AirFlow::Mass(1.)), "Outlet humidity ratio must not be below the humidity ratio of the air")]
+    #[case(PsychrometricState::from_relative_humidity(Celsius(20.), RelativeHumidity::new(30.).unwrap(), BarometricPressure(1_013.25)).steam_humidification(0.02, AirFlow::Mass(1.)), "Air cannot be supersaturated")]
+    fn test_psychrometric_process_errors(
+        #[case] process: Result<PsychrometricProcess<Celsius>, &'static str>,
+        #[case] expected_error: &'static str,
+    ) {
+        assert_eq!(process.err(), Some(expected_error));
+    }
+
+    #[rstest]
+    fn test_psychrometric_mixing_into_fog() {
+        assert_eq!(
+            PsychrometricState::from_relative_humidity(
+                Celsius(-10.),
+                RelativeHumidity::new(100.).unwrap(),
+                BarometricPressure(1_013.25)
+            )
+            .mix(
+                AirFlow::Mass(1.),
+                &PsychrometricState::from_relative_humidity(
+                    Celsius(30.),
+                    RelativeHumidity::new(100.).unwrap(),
+                    BarometricPressure(1_013.25)
+                ),
+                AirFlow::Mass(1.)
+            )
+            .err(),
+            Some("Air cannot be supersaturated")
+        );
+    }
+}
@@ -0,0 +1,820 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use crate::atmosphere::{
+    dew_point, equivalent_potential_temperature, mixing_ratio, precipitable_water,
+    saturation_vapour_pressure, virtual_temperature, EPSILON, GAS_CONSTANT_AIR, KAPPA, KELVIN,
+    SNOW_LEVEL_DEPTH,
+};
+use crate::psychrometrics::solve;
+use crate::PsychrometricState;
+use crate::{Altitude, BarometricPressure, Celsius, PrecipitableWater, Temperature};
+
+/// The step of the parcel ascent, in logarithm of the pressure.
+const ASCENT_STEP: f32 = 0.01;
+/// The depth of the mixed layer (in hPa).
+const MIXED_LAYER_DEPTH: f32 = 100.;
+/// The depth of the layer searched for the most unstable parcel (in hPa).
+const MOST_UNSTABLE_LAYER_DEPTH: f32 = 300.;
+/// The number of samples used to average the mixed layer.
+const MIXED_LAYER_SAMPLES: usize = 10;
+
+/// A level of an atmospheric sounding.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SoundingLevel<T: Temperature> {
+    /// The barometric pressure (in hPa).
+    pub barometric_pressure: BarometricPressure,
+    /// The temperature (either in °C or °F).
+    pub temperature: T,
+    /// The dew point (either in °C or °F).
+    pub dew_point: T,
+    /// The height (in m).
+    pub height: Altitude,
+}
+
+impl<T: Temperature + PartialEq> PartialEq for SoundingLevel<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.barometric_pressure.eq(&other.barometric_pressure)
+            && self.temperature.eq(&other.temperature)
+            && self.dew_point.eq(&other.dew_point)
+            && self.height.eq(&other.height)
+    }
+}
+
+/// The air parcel lifted through a sounding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParcelType {
+    /// The parcel starting from the lowest level of the sounding.
+    #[default]
+    SurfaceBased,
+    /// The parcel with the mean potential temperature and mixing ratio of the lowest 100 hPa,
+    /// starting from the lowest level of the sounding.
+    MixedLayer,
+    /// The parcel with the highest equivalent potential temperature in the lowest 300 hPa,
+    /// starting from its level.
+    MostUnstable,
+}
+
+/// The result of the ascent of an air parcel through a sounding.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParcelAnalysis<T: Temperature> {
+    parcel: SoundingLevel<T>,
+    lifting_condensation_level: BarometricPressure,
+    level_of_free_convection: Option<BarometricPressure>,
+    equilibrium_level: Option<BarometricPressure>,
+    cape: f32,
+    cin: f32,
+    lifted_index: Option<f32>,
+}
+
+impl<T: Temperature> ParcelAnalysis<T> {
+    /// Get the initial state of the parcel (its height is the height of the sounding at the
+    /// initial pressure of the parcel).
+    pub fn parcel(&self) -> SoundingLevel<T> {
+        self.parcel
+    }
+
+    /// Get the pressure of the lifting condensation level (in hPa).
+    pub fn lifting_condensation_level(&self) -> BarometricPressure {
+        self.lifting_condensation_level
+    }
+
+    /// Get the pressure of the level of free convection (LFC, in hPa), the level above which
+    /// the parcel is warmer than the environment, if any.
+    pub fn level_of_free_convection(&self) -> Option<BarometricPressure> {
+        self.level_of_free_convection
+    }
+
+    /// Get the pressure of the equilibrium level (EL, in hPa), the level above which the parcel
+    /// becomes colder than the environment again, if it is within the sounding.
+    pub fn equilibrium_level(&self) -> Option<BarometricPressure> {
+        self.equilibrium_level
+    }
+
+    /// Get the convective available potential energy (CAPE, in J/kg), the positive buoyant
+    /// energy of the parcel between the LFC and the EL.
+    pub fn cape(&self) -> f32 {
+        self.cape
+    }
+
+    /// Get the convective inhibition (CIN, in J/kg), the negative buoyant energy of the parcel
+    /// below the LFC. It is negative or zero.
+    pub fn cin(&self) -> f32 {
+        self.cin
+    }
+
+    /// Get the lifted index (in °C), the difference between the temperature of the environment
+    /// and of the parcel at 500 hPa, if the sounding reaches 500 hPa.
+    pub fn lifted_index(&self) -> Option<f32> {
+        self.lifted_index
+    }
+}
+
+/// An atmospheric sounding.
+///
+/// A sounding is a vertical profile of the atmosphere, as measured by a radiosonde. The levels
+/// are ordered from the ground to the top, and values between levels are interpolated linearly
+/// in the logarithm of the pressure.
+/// Air parcels are lifted along the dry adiabat up to their lifting condensation level, and
+/// along the pseudo-adiabat (conserving the equivalent potential temperature of Bolton (1980))
+/// above. The buoyancy is computed from the virtual temperatures.
+#[derive(Clone, Copy, Debug)]
+pub struct Sounding<'a, T: Temperature> {
+    levels: &'a [SoundingLevel<T>],
+}
+
+impl<'a, T: Temperature> Sounding<'a, T> {
+    /// Create a Sounding from its levels, ordered from the ground to the top.
+    ///
+    /// Returns an error if there are less than two levels, if the pressure does not strictly
+    /// decrease with the levels, or if a dew point is above the temperature.
+    pub fn new(levels: &'a [SoundingLevel<T>]) -> Result<Self, &'static str> {
+        if levels.len() < 2 {
+            return Err("A sounding needs at least two levels");
+        }
+        if levels
+            .windows(2)
+            .any(|pair| pair[1].barometric_pressure.value() >= pair[0].barometric_pressure.value())
+        {
+            return Err("Pressure must strictly decrease with the levels");
+        }
+        if levels
+            .iter()
+            .any(|level| level.dew_point.celsius().value() > level.temperature.celsius().value())
+        {
+            return Err("Dew point must not be above temperature");
+        }
+        Ok(Self { levels })
+    }
+
+    /// Get the levels of the sounding.
+    pub fn levels(&self) -> &'a [SoundingLevel<T>] {
+        self.levels
+    }
+
+    /// Interpolates the sounding at the given pressure, linearly in the logarithm of the
+    /// pressure.
+    ///
+    /// Returns `None` if the pressure is outside of the sounding.
+    pub fn interpolate(&self, barometric_pressure: BarometricPressure) -> Option<SoundingLevel<T>> {
+        let (temperature, dew_point, height) =
+            self.interpolate_celsius(barometric_pressure.value())?;
+        Some(SoundingLevel {
+            barometric_pressure,
+            temperature: T::from_celsius(Celsius(temperature)),
+            dew_point: T::from_celsius(Celsius(dew_point)),
+            height: Altitude(height),
+        })
+    }
+
+    /// Lifts the given parcel through the sounding.
+    ///
+    /// See [`ParcelAnalysis`].
+    pub fn parcel_analysis(&self, parcel_type: ParcelType) -> ParcelAnalysis<T> {
+        let parcel = match parcel_type {
+            ParcelType::SurfaceBased => self.surface_based_parcel(),
+            ParcelType::MixedLayer => self.mixed_layer_parcel(),
+            ParcelType::MostUnstable => self.most_unstable_parcel(),
+        };
+        let lifted_index = self.lifted_index(&parcel);
+        let (level_of_free_convection, equilibrium_level, cape, cin) = self.ascent(&parcel);
+        ParcelAnalysis {
+            parcel: SoundingLevel {
+                barometric_pressure: BarometricPressure(parcel.pressure),
+                temperature: T::from_celsius(Celsius(parcel.temperature - KELVIN)),
+                dew_point: T::from_celsius(Celsius(parcel.dew_point - KELVIN)),
+                height: Altitude(
+                    self.interpolate_celsius(parcel.pressure)
+                        .map_or(0., |(_, _, height)| height),
+                ),
+            },
+            lifting_condensation_level: BarometricPressure(parcel.lcl_pressure),
+            level_of_free_convection: level_of_free_convection.map(BarometricPressure),
+            equilibrium_level: equilibrium_level.map(BarometricPressure),
+            cape,
+            cin,
+            lifted_index,
+        }
+    }
+
+    /// Computes the K-index (in °C), an indicator of the thunderstorm potential from the
+    /// temperature lapse rate between 850 and 500 hPa and the moisture at 850 and 700 hPa.
+    ///
+    /// Returns `None` if the sounding does not cover the 850 to 500 hPa layer.
+    pub fn k_index(&self) -> Option<f32> {
+        let (temperature_850, dew_point_850, _) = self.interpolate_celsius(850.)?;
+        let (temperature_700, dew_point_700, _) = self.interpolate_celsius(700.)?;
+        let (temperature_500, _, _) = self.interpolate_celsius(500.)?;
+        Some(temperature_850 - temperature_500 + dew_point_850 - (temperature_700 - dew_point_700))
+    }
+
+    /// Computes the Total Totals index (in °C), the sum of the vertical totals (temperature
+    /// difference between 850 and 500 hPa) and of the cross totals (difference between the dew
+    /// point at 850 hPa and the temperature at 500 hPa).
+    ///
+    /// Returns `None` if the sounding does not cover the 850 to 500 hPa layer.
+    pub fn total_totals(&self) -> Option<f32> {
+        let (temperature_850, dew_point_850, _) = self.interpolate_celsius(850.)?;
+        let (temperature_500, _, _) = self.interpolate_celsius(500.)?;
+        Some(temperature_850 + dew_point_850 - 2. * temperature_500)
+    }
+
+    /// Computes the Showalter index (in °C), the lifted index of a parcel lifted from 850 hPa.
+    ///
+    /// Returns `None` if the sounding does not cover the 850 to 500 hPa layer.
+    pub fn showalter_index(&self) -> Option<f32> {
+        let (temperature, dew_point, _) = self.interpolate_celsius(850.)?;
+        self.lifted_index(&Parcel::new(850., temperature, dew_point))
+    }
+
+    /// Computes the total precipitable water (in mm) of the sounding, integrating the specific
+    /// humidity over the pressure with the trapezoidal rule.
+    pub fn precipitable_water(&self) -> PrecipitableWater {
+        precipitable_water(self.levels.iter().map(|level| {
+            (
+                level.barometric_pressure.value(),
+                saturation_vapour_pressure(level.dew_point.celsius().value()),
+            )
+        }))
+    }
+
+    /// Computes the freezing level, the height of the 0°C isotherm.
+    ///
+    /// The freezing level is the lowest height at which the temperature falls below 0°C, it is
+    /// the height of the ground when the temperature is at or below 0°C at the ground. Returns
+    /// `None` if the temperature is above 0°C in the whole sounding.
+    pub fn freezing_level(&self) -> Option<Altitude> {
+        self.zero_height(|level| Some(level.temperature.celsius().value()))
+    }
+
+    /// Computes the wet-bulb zero height, the height at which the wet-bulb temperature reaches
+    /// 0°C.
+    ///
+    /// The wet-bulb zero height is the lowest height at which the wet-bulb temperature falls
+    /// below 0°C, it is the height of the ground when the wet-bulb temperature is at or below
+    /// 0°C at the ground. Returns `None` if the wet-bulb temperature is above 0°C in the whole
+    /// sounding.
+    pub fn wet_bulb_zero_height(&self) -> Option<Altitude> {
+        self.zero_height(|level| {
+            PsychrometricState::from_dew_point(
+                level.temperature,
+                level.dew_point,
+                level.barometric_pressure,
+            )
+            .ok()
+            .map(|state| state.wet_bulb().celsius().value())
+        })
+    }
+
+    /// Estimates the snow level, the height down to which precipitation falls as snow.
+    ///
+    /// Melting snow cools the air down, so snow usually reaches about 300 m below the
+    /// [wet-bulb zero height](Self::wet_bulb_zero_height). Returns `None` if the wet-bulb
+    /// temperature is above 0°C in the whole sounding.
+    pub fn snow_level(&self) -> Option<Altitude> {
+        self.wet_bulb_zero_height()
+            .map(|height| Altitude(height.value() - SNOW_LEVEL_DEPTH))
+    }
+
+    /// Computes the lowest height (in m) at which the given temperature (in °C) of the levels
+    /// falls below 0°C, interpolating linearly between the levels.
+    fn zero_height(
+        &self,
+        temperature: impl Fn(&SoundingLevel<T>) -> Option<f32>,
+    ) -> Option<Altitude> {
+        let ground = &self.levels[0];
+        if temperature(ground)? <= 0. {
+            return Some(ground.height);
+        }
+        self.levels.windows(2).find_map(|pair| {
+            let (lower, upper) = (&pair[0], &pair[1]);
+            let lower_temperature = temperature(lower)?;
+            let upper_temperature = temperature(upper)?;
+            if lower_temperature <= 0. || upper_temperature > 0. {
+                return None;
+            }
+            let ratio = lower_temperature / (lower_temperature - upper_temperature);
+            Some(Altitude(
+                lower.height.value() + ratio * (upper.height.value() - lower.height.value()),
+            ))
+        })
+    }
+
+    /// Interpolates the temperature, the dew point (both in °C) and the height (in m) at the
+    /// given pressure (in hPa).
+    fn interpolate_celsius(&self, pressure: f32) -> Option<(f32, f32, f32)> {
+        self.levels.windows(2).find_map(|pair| {
+            let (lower, upper) = (&pair[0], &pair[1]);
+            let lower_pressure = lower.barometric_pressure.value();
+            let upper_pressure = upper.barometric_pressure.value();
+            if !(upper_pressure..=lower_pressure).contains(&pressure) {
+                return None;
+            }
+            let weight = (pressure / lower_pressure).ln() / (upper_pressure / lower_pressure).ln();
+            let interpolate = |lower: f32, upper: f32| lower + weight * (upper - lower);
+            Some((
+                interpolate(
+                    lower.temperature.celsius().value(),
+                    upper.temperature.celsius().value(),
+                ),
+                interpolate(
+                    lower.dew_point.celsius().value(),
+                    upper.dew_point.celsius().value(),
+                ),
+                interpolate(lower.height.value(), upper.height.value()),
+            ))
+        })
+    }
+
+    /// Computes the virtual temperature of the environment (in K) at the given pressure
+    /// (in hPa).
+    fn virtual_temperature(&self, pressure: f32) -> f32 {
+        let (temperature, dew_point, _) = self.interpolate_celsius(pressure).unwrap_or_default();
+        virtual_temperature(temperature + KELVIN, mixing_ratio(dew_point, pressure))
+    }
+
+    /// Get the pressure of the lowest and of the highest levels (in hPa).
+    fn pressure_range(&self) -> (f32, f32) {
+        (
+            self.levels[0].barometric_pressure.value(),
+            self.levels[self.levels.len() - 1]
+                .barometric_pressure
+                .value(),
+        )
+    }
+
+    /// Creates the surface based parcel.
+    fn surface_based_parcel(&self) -> Parcel {
+        let level = &self.levels[0];
+        Parcel::new(
+            level.barometric_pressure.value(),
+            level.temperature.celsius().value(),
+            level.dew_point.celsius().value(),
+        )
+    }
+
+    /// Creates the mixed layer parcel.
+    fn mixed_layer_parcel(&self) -> Parcel {
+        let (surface_pressure, top_pressure) = self.pressure_range();
+        let depth = MIXED_LAYER_DEPTH.min(surface_pressure - top_pressure);
+        let (mut potential_temperature, mut mixing_ratio_sum) = (0., 0.);
+        for sample in 0..=MIXED_LAYER_SAMPLES {
+            let pressure = surface_pressure - depth * sample as f32 / MIXED_LAYER_SAMPLES as f32;
+            let (temperature, dew_point, _) =
+                self.interpolate_celsius(pressure).unwrap_or_default();
+            potential_temperature += (temperature + KELVIN) * (1_000. / pressure).powf(KAPPA);
+            mixing_ratio_sum += mixing_ratio(dew_point, pressure);
+        }
+        let samples = (MIXED_LAYER_SAMPLES + 1) as f32;
+        let temperature =
+            potential_temperature / samples * (surface_pressure / 1_000.).powf(KAPPA) - KELVIN;
+        let mixing_ratio = mixing_ratio_sum / samples;
+        Parcel::new(
+            surface_pressure,
+            temperature,
+            dew_point(mixing_ratio * surface_pressure / (EPSILON + mixing_ratio)),
+        )
+    }
+
+    /// Creates the most unstable parcel.
+    fn most_unstable_parcel(&self) -> Parcel {
+        let (surface_pressure, _) = self.pressure_range();
+        self.levels
+            .iter()
+            .take_while(|level| {
+                level.barometric_pressure.value() >= surface_pressure - MOST_UNSTABLE_LAYER_DEPTH
+            })
+            .map(|level| {
+                Parcel::new(
+                    level.barometric_pressure.value(),
+                    level.temperature.celsius().value(),
+                    level.dew_point.celsius().value(),
+                )
+            })
+            .fold(
+                None,
+                |most_unstable: Option<Parcel>, parcel| match most_unstable {
+                    Some(most_unstable)
+                        if most_unstable.equivalent_potential_temperature
+                            >= parcel.equivalent_potential_temperature =>
+                    {
+                        Some(most_unstable)
+                    }
+                    _ => Some(parcel),
+                },
+            )
+            .unwrap_or_else(|| self.surface_based_parcel())
+    }
+
+    /// Computes the lifted index (in °C) of the given parcel.
+    fn lifted_index(&self, parcel: &Parcel) -> Option<f32> {
+        if parcel.pressure < 500. {
+            return None;
+        }
+        let (temperature, _, _) = self.interpolate_celsius(500.)?;
+        Some(temperature + KELVIN - parcel.temperature(500.))
+    }
+
+    /// Lifts the given parcel through the sounding, returning its level of free convection and
+    /// equilibrium level (in hPa), its CAPE and its CIN (in J/kg).
+    fn ascent(&self, parcel: &Parcel) -> (Option<f32>, Option<f32>, f32, f32) {
+        let (_, top_pressure) = self.pressure_range();
+        let bottom = parcel.pressure.ln();
+        let top = top_pressure.ln();
+        let steps = ((bottom - top) / ASCENT_STEP).ceil().max(1.) as usize;
+        let pressure = |step: usize| {
+            if step == 0 {
+                parcel.pressure
+            } else if step == steps {
+                top_pressure
+            } else {
+                (bottom - (bottom - top) * step as f32 / steps as f32).exp()
+            }
+        };
+        let buoyancy = |pressure: f32| {
+            parcel.virtual_temperature(pressure) - self.virtual_temperature(pressure)
+        };
+
+        let mut level_of_free_convection = None;
+        let mut previous = (pressure(0), buoyancy(pressure(0)));
+        for step in 0..=steps {
+            let current = (pressure(step), buoyancy(pressure(step)));
+            if current.0 <= parcel.lcl_pressure && current.1 > 0. {
+                level_of_free_convection = Some(if step > 0 && previous.0 <= parcel.lcl_pressure {
+                    crossing(previous, current)
+                } else {
+                    parcel.lcl_pressure
+                });
+                break;
+            }
+            previous = current;
+        }
+        let Some(level_of_free_convection) = level_of_free_convection else {
+            return (None, None, 0., 0.);
+        };
+
+        let mut equilibrium_level = None;
+        let mut previous = (pressure(0), buoyancy(pressure(0)));
+        for step in 1..=steps {
+            let current = (pressure(step), buoyancy(pressure(step)));
+            if current.0 < level_of_free_convection && previous.1 > 0. && current.1 <= 0. {
+                equilibrium_level = Some(crossing(previous, current));
+            }
+            previous = current;
+        }
+
+        let (mut cape, mut cin) = (0., 0.);
+        let mut previous = (pressure(0), buoyancy(pressure(0)));
+        for step in 1..=steps {
+            let current = (pressure(step), buoyancy(pressure(step)));
+            cape += area(
+                previous,
+                current,
+                level_of_free_convection,
+                equilibrium_level.unwrap_or(top_pressure),
+            );
+            cin += area(
+                (previous.0, previous.1.min(0.)),
+                (current.0, current.1.min(0.)),
+                parcel.pressure,
+                level_of_free_convection,
+            );
+            previous = current;
+        }
+        (
+            Some(level_of_free_convection),
+            equilibrium_level,
+            GAS_CONSTANT_AIR * cape,
+            GAS_CONSTANT_AIR * cin,
+        )
+    }
+}
+
+/// An air parcel (pressures in hPa and temperatures in K).
+#[derive(Clone, Copy, Debug)]
+struct Parcel {
+    pressure: f32,
+    temperature: f32,
+    dew_point: f32,
+    mixing_ratio: f32,
+    lcl_pressure: f32,
+    lcl_temperature: f32,
+    equivalent_potential_temperature: f32,
+}
+
+impl Parcel {
+    /// Create a Parcel from its pressure (in hPa), temperature and dew point (both in °C).
+    fn new(pressure: f32, temperature: f32, dew_point: f32) -> Self {
+        let temperature_kelvin = temperature + KELVIN;
+        let dew_point_kelvin = dew_point + KELVIN;
+        // Temperature at the lifting condensation level, formula (15) of Bolton (1980).
+        let lcl_temperature = 1.
+            / (1. / (dew_point_kelvin - 56.) + (temperature_kelvin / dew_point_kelvin).ln() / 800.)
+            + 56.;
+        Self {
+            pressure,
+            temperature: temperature_kelvin,
+            dew_point: dew_point_kelvin,
+            mixing_ratio: mixing_ratio(dew_point, pressure),
+            lcl_pressure: pressure * (lcl_temperature / temperature_kelvin).powf(1. / KAPPA),
+            lcl_temperature,
+            equivalent_potential_temperature: equivalent_potential_temperature(
+                temperature_kelvin,
+                100. * saturation_vapour_pressure(dew_point)
+                    / saturation_vapour_pressure(temperature),
+                pressure,
+            ),
+        }
+    }
+
+    /// Computes the temperature of the parcel (in K) lifted to the given pressure (in hPa).
+    fn temperature(&self, pressure: f32) -> f32 {
+        if pressure >= self.lcl_pressure {
+            self.temperature * (pressure / self.pressure).powf(KAPPA)
+        } else {
+            solve(150., self.lcl_temperature, |temperature| {
+                equivalent_potential_temperature(temperature, 100., pressure)
+                    - self.equivalent_potential_temperature
+            })
+        }
+    }
+
+    /// Computes the virtual temperature of the parcel (in K) lifted to the given pressure
+    /// (in hPa).
+    fn virtual_temperature(&self, pressure: f32) -> f32 {
+        let temperature = self.temperature(pressure);
+        if pressure >= self.lcl_pressure {
+            virtual_temperature(temperature, self.mixing_ratio)
+        } else {
+            virtual_temperature(temperature, mixing_ratio(temperature - KELVIN, pressure))
+        }
+    }
+}
+
+/// Computes the pressure (in hPa) at which the buoyancy crosses zero between two points
+/// (pressure in hPa, buoyancy), linearly in the logarithm of the pressure.
+fn crossing(lower: (f32, f32), upper: (f32, f32)) -> f32 {
+    let (lower_pressure, lower_buoyancy) = lower;
+    let (upper_pressure, upper_buoyancy) = upper;
+    (lower_pressure.ln()
+        + (upper_pressure / lower_pressure).ln() * lower_buoyancy
+            / (lower_buoyancy - upper_buoyancy))
+        .exp()
+}
+
+/// Computes the integral of the buoyancy over the logarithm of the pressure between two points
+/// (pressure in hPa, buoyancy), restricted to the layer between the given bottom and top
+/// pressures (in hPa).
+fn area(lower: (f32, f32), upper: (f32, f32), bottom: f32, top: f32) -> f32 {
+    let (lower_x, lower_buoyancy) = (lower.0.ln(), lower.1);
+    let (upper_x, upper_buoyancy) = (upper.0.ln(), upper.1);
+    let high = lower_x.min(bottom.ln());
+    let low = upper_x.max(top.ln());
+    if high <= low {
+        return 0.;
+    }
+    let buoyancy = |x: f32| {
+        lower_buoyancy + (upper_buoyancy - lower_buoyancy) * (x - lower_x) / (upper_x - lower_x)
+    };
+    (high - low) * (buoyancy(high) + buoyancy(low)) / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Fahrenheit;
+
+    fn level<T: Temperature>(
+        barometric_pressure: f32,
+        temperature: T,
+        dew_point: T,
+        height: f32,
+    ) -> SoundingLevel<T> {
+        SoundingLevel {
+            barometric_pressure: BarometricPressure(barometric_pressure),
+            temperature,
+            dew_point,
+            height: Altitude(height),
+        }
+    }
+
+    /// A warm and humid sounding, unstable for surface based parcels.
+    fn unstable() -> [SoundingLevel<Celsius>; 14] {
+        [
+            level(1_000., Celsius(30.), Celsius(22.), 100.),
+            level(950., Celsius(26.), Celsius(20.), 560.),
+            level(900., Celsius(22.5), Celsius(17.), 1_020.),
+            level(850., Celsius(19.5), Celsius(14.), 1_500.),
+            level(800., Celsius(16.), Celsius(10.), 2_000.),
+            level(700., Celsius(9.), Celsius(0.), 3_100.),
+            level(600., Celsius(1.), Celsius(-10.), 4_300.),
+            level(500., Celsius(-8.), Celsius(-22.), 5_700.),
+            level(400., Celsius(-19.), Celsius(-35.), 7_350.),
+            level(300., Celsius(-34.), Celsius(-48.), 9_400.),
+            level(250., Celsius(-44.), Celsius(-55.), 10_600.),
+            level(200., Celsius(-54.), Celsius(-63.), 12_000.),
+            level(150., Celsius(-60.), Celsius(-70.), 13_700.),
+            level(100., Celsius(-66.), Celsius(-80.), 16_300.),
+        ]
+    }
+
+    /// A cool and dry sounding, stable for all parcels.
+    fn stable() -> [SoundingLevel<Celsius>; 7] {
+        [
+            level(1_000., Celsius(15.), Celsius(5.), 100.),
+            level(925., Celsius(14.), Celsius(0.), 770.),
+            level(850., Celsius(10.), Celsius(-5.), 1_460.),
+            level(700., Celsius(2.), Celsius(-15.), 3_000.),
+            level(500., Celsius(-15.), Celsius(-30.), 5_600.),
+            level(300., Celsius(-40.), Celsius(-55.), 9_200.),
+            level(200., Celsius(-55.), Celsius(-70.), 11_800.),
+        ]
+    }
+
+    /// A sounding with a dry surface layer under a moist layer, unstable for elevated parcels.
+    fn elevated() -> [SoundingLevel<Celsius>; 8] {
+        [
+            level(1_000., Celsius(22.), Celsius(10.), 100.),
+            level(950., Celsius(24.), Celsius(8.), 550.),
+            level(900., Celsius(22.), Celsius(18.), 1_000.),
+            level(850., Celsius(19.), Celsius(16.), 1_480.),
+            level(700., Celsius(8.), Celsius(2.), 3_100.),
+            level(500., Celsius(-10.), Celsius(-20.), 5_700.),
+            level(300., Celsius(-36.), Celsius(-50.), 9_400.),
+            level(200., Celsius(-55.), Celsius(-65.), 12_000.),
+        ]
+    }
+
+    #[rstest]
+    #[case(&unstable(), ParcelType::SurfaceBased, (1_000., 30., 22.), 890.32, (2_562.3, -24.53), (Some(839.37), Some(182.53)), -6.18)]
+    #[case(&unstable(), ParcelType::MixedLayer, (1_000., 30.61, 20.6), 864.77, (1_890.9, -33.35), (Some(806.56), Some(192.48)), -4.96)]
+    #[case(&unstable(), ParcelType::MostUnstable, (1_000., 30., 22.), 890.32, (2_562.3, -24.53), (Some(839.37), Some(182.53)), -6.18)]
+    #[case(&stable(), ParcelType::SurfaceBased, (1_000., 15., 5.), 859.95, (0., 0.), (None, None), 11.26)]
+    #[case(&stable(), ParcelType::MixedLayer, (1_000., 18.48, 2.5), 786.72, (0., 0.), (None, None), 10.43)]
+    #[case(&stable(), ParcelType::MostUnstable, (700., 2., -15.), 535.99, (0., 0.), (None, None), 7.16)]
+    #[case(&elevated(), ParcelType::SurfaceBased, (1_000., 22., 10.), 836.82, (0., 0.), (None, None), 7.67)]
+    #[case(&elevated(), ParcelType::MostUnstable, (900., 22., 18.), 848.38, (2_071., -29.84), (Some(793.5), None), -6.19)]
+    fn test_parcel_analysis(
+        #[case] levels: &[SoundingLevel<Celsius>],
+        #[case] parcel_type: ParcelType,
+        #[case] expected_parcel: (f32, f32, f32),
+        #[case] expected_lifting_condensation_level: f32,
+        #[case] expected_energies: (f32, f32),
+        #[case] expected_levels: (Option<f32>, Option<f32>),
+        #[case] expected_lifted_index: f32,
+    ) {
+        let analysis = Sounding::new(levels).unwrap().parcel_analysis(parcel_type);
+        let parcel = analysis.parcel();
+        assert_relative_eq!(
+            parcel.barometric_pressure.value(),
+            expected_parcel.0,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            parcel.temperature.value(),
+            expected_parcel.1,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(parcel.dew_point.value(), expected_parcel.2, epsilon = 0.01);
+        assert_relative_eq!(
+            analysis.lifting_condensation_level().value(),
+            expected_lifting_condensation_level,
+            epsilon = 0.1
+        );
+        assert_relative_eq!(analysis.cape(), expected_energies.0, epsilon = 1.);
+        assert_relative_eq!(analysis.cin(), expected_energies.1, epsilon = 0.1);
+        assert_eq!(
+            analysis.level_of_free_convection(),
+            expected_levels.0.map(BarometricPressure)
+        );
+        assert_eq!(
+            analysis.equilibrium_level(),
+            expected_levels.1.map(BarometricPressure)
+        );
+        assert_relative_eq!(
+            analysis.lifted_index().unwrap(),
+            expected_lifted_index,
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(&unstable(), Some(-2.22), Some(32.5), Some(49.5))]
+    #[case(&stable(), Some(9.79), Some(3.), Some(35.))]
+    #[case(&elevated(), Some(-5.89), Some(39.), Some(55.))]
+    #[case(&unstable()[..6], None, None, None)]
+    fn test_stability_indices(
+        #[case] levels: &[SoundingLevel<Celsius>],
+        #[case] expected_showalter_index: Option<f32>,
+        #[case] expected_k_index: Option<f32>,
+        #[case] expected_total_totals: Option<f32>,
+    ) {
+        let sounding = Sounding::new(levels).unwrap();
+        for (value, expected) in [
+            (sounding.showalter_index(), expected_showalter_index),
+            (sounding.k_index(), expected_k_index),
+            (sounding.total_totals(), expected_total_totals),
+        ] {
+            match (value, expected) {
+                (Some(value), Some(expected)) => {
+                    assert_relative_eq!(value, expected, epsilon = 0.01)
+                }
+                (value, expected) => assert_eq!(value, expected),
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(&unstable(), BarometricPressure(850.), Some((19.5, 14., 1_500.)))]
+    #[case(&unstable(), BarometricPressure(925.), Some((24.27, 18.52, 786.89)))]
+    #[case(&unstable(), BarometricPressure(1_020.), None)]
+    #[case(&unstable(), BarometricPressure(50.), None)]
+    fn test_sounding_interpolation(
+        #[case] levels: &[SoundingLevel<Celsius>],
+        #[case] barometric_pressure: BarometricPressure,
+        #[case] expected: Option<(f32, f32, f32)>,
+    ) {
+        let value = Sounding::new(levels)
+            .unwrap()
+            .interpolate(barometric_pressure);
+        match (value, expected) {
+            (Some(value), Some(expected)) => {
+                assert_eq!(value.barometric_pressure, barometric_pressure);
+                assert_relative_eq!(value.temperature.value(), expected.0, epsilon = 0.01);
+                assert_relative_eq!(value.dew_point.value(), expected.1, epsilon = 0.01);
+                assert_relative_eq!(value.height.value(), expected.2, epsilon = 0.01);
+            }
+            (value, expected) => assert_eq!(value.is_some(), expected.is_some()),
+        }
+    }
+
+    #[rstest]
+    #[case(&unstable(), 42.95)]
+    #[case(&stable(), 13.23)]
+    fn test_sounding_precipitable_water(
+        #[case] levels: &[SoundingLevel<Celsius>],
+        #[case] expected_precipitable_water: PrecipitableWater,
+    ) {
+        assert_relative_eq!(
+            Sounding::new(levels).unwrap().precipitable_water(),
+            expected_precipitable_water,
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(&unstable(), Some(4_455.56), Some(3_708.48), Some(3_408.48))]
+    #[case(&stable(), Some(3_305.88), Some(2_110.06), Some(1_810.06))]
+    #[case(&elevated(), Some(4_255.56), Some(3_782.14), Some(3_482.14))]
+    #[case(&[level(1_000., Celsius(-2.), Celsius(-4.), 100.), level(850., Celsius(-8.), Celsius(-12.), 1_400.)], Some(100.), Some(100.), Some(-200.))]
+    #[case(&unstable()[..5], None, None, None)]
+    fn test_sounding_freezing_and_snow_levels(
+        #[case] levels: &[SoundingLevel<Celsius>],
+        #[case] expected_freezing_level: Option<f32>,
+        #[case] expected_wet_bulb_zero_height: Option<f32>,
+        #[case] expected_snow_level: Option<f32>,
+    ) {
+        let sounding = Sounding::new(levels).unwrap();
+        assert_eq!(
+            sounding.freezing_level(),
+            expected_freezing_level.map(Altitude)
+        );
+        assert_eq!(
+            sounding.wet_bulb_zero_height(),
+            expected_wet_bulb_zero_height.map(Altitude)
+        );
+        assert_eq!(sounding.snow_level(), expected_snow_level.map(Altitude));
+    }
+
+    #[rstest]
+    fn test_sounding_in_fahrenheit() {
+        let levels = unstable().map(|level| SoundingLevel {
+            barometric_pressure: level.barometric_pressure,
+            temperature: level.temperature.fahrenheit(),
+            dew_point: level.dew_point.fahrenheit(),
+            height: level.height,
+        });
+        let sounding = Sounding::<Fahrenheit>::new(&levels).unwrap();
+        let analysis = sounding.parcel_analysis(ParcelType::SurfaceBased);
+        assert_relative_eq!(analysis.parcel().temperature.value(), 86., epsilon = 0.01);
+        assert_relative_eq!(analysis.cape(), 2_562.3, epsilon = 2.);
+        assert_relative_eq!(sounding.k_index().unwrap(), 32.5, epsilon = 0.01);
+    }
+
+    #[rstest]
+    #[case(&unstable()[..1], "A sounding needs at least two levels")]
+    #[case(&[level(850., Celsius(20.), Celsius(10.), 1_500.), level(900., Celsius(22.), Celsius(12.), 1_000.)], "Pressure must strictly decrease with the levels")]
+    #[case(&[level(1_000., Celsius(20.), Celsius(21.), 100.), level(900., Celsius(15.), Celsius(12.), 1_000.)], "Dew point must not be above temperature")]
+    fn test_sounding_errors(
+        #[case] levels: &[SoundingLevel<Celsius>],
+        #[case] expected_error: &'static str,
+    ) {
+        assert_eq!(Sounding::new(levels).err(), Some(expected_error));
+    }
+}
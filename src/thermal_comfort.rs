@@ -0,0 +1,524 @@
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use core::ops::Deref;
+
+use approx::relative_eq;
+
+use crate::{Celsius, Fahrenheit, RelativeHumidity, Temperature, WindSpeed};
+
+/// The predicted mean vote (PMV).
+///
+/// The PMV predicts the mean value of the votes of a large group of persons on the 7-point
+/// thermal sensation scale: +3 hot, +2 warm, +1 slightly warm, 0 neutral, -1 slightly cool,
+/// -2 cool and -3 cold.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PredictedMeanVote(f32);
+
+impl PredictedMeanVote {
+    /// Get the value of the predicted mean vote.
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+
+    /// Computes the predicted percentage of dissatisfied (PPD, in %), that is the percentage of
+    /// persons predicted to feel too warm or too cool.
+    pub fn ppd(&self) -> f32 {
+        100. - 95. * (-0.033_53 * self.0.powi(4) - 0.2179 * self.0.powi(2)).exp()
+    }
+
+    /// Get the ISO 7730 category of the thermal environment corresponding to the predicted mean
+    /// vote.
+    pub fn category(&self) -> ThermalEnvironmentCategory {
+        if self.0.abs() < 0.2 {
+            ThermalEnvironmentCategory::A
+        } else if self.0.abs() < 0.5 {
+            ThermalEnvironmentCategory::B
+        } else if self.0.abs() < 0.7 {
+            ThermalEnvironmentCategory::C
+        } else {
+            ThermalEnvironmentCategory::OutOfCategories
+        }
+    }
+}
+
+impl From<f32> for PredictedMeanVote {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq for PredictedMeanVote {
+    fn eq(&self, other: &Self) -> bool {
+        relative_eq!(self.0, other.0, epsilon = 0.01)
+    }
+}
+
+/// The category of a thermal environment, as defined by ISO 7730.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThermalEnvironmentCategory {
+    /// Category A: PMV between -0.2 and +0.2, PPD below 6%.
+    A,
+    /// Category B: PMV between -0.5 and +0.5, PPD below 10%.
+    B,
+    /// Category C: PMV between -0.7 and +0.7, PPD below 15%.
+    C,
+    /// Outside of the categories: PMV below -0.7 or above +0.7.
+    OutOfCategories,
+}
+
+/// The parameters of a thermal environment and of its occupants.
+///
+/// The PMV model is meant to be used with an air temperature between 10°C and 30°C, a mean
+/// radiant temperature between 10°C and 40°C, an air speed between 0 and 1 m/s, a metabolic
+/// rate between 0.8 and 4 met and a clothing insulation between 0 and 2 clo.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThermalEnvironment<T: Temperature> {
+    /// The air temperature (either in °C or °F).
+    pub air_temperature: T,
+    /// The mean radiant temperature (either in °C or °F).
+    pub mean_radiant_temperature: T,
+    /// The relative air speed (in m/s).
+    pub air_speed: WindSpeed,
+    /// The relative humidity (in %).
+    pub relative_humidity: RelativeHumidity,
+    /// The metabolic rate (in met, 1 met = 58.15 W/m²).
+    pub metabolic_rate: f32,
+    /// The clothing insulation (in clo, 1 clo = 0.155 m²·K/W).
+    pub clothing_insulation: f32,
+}
+
+impl<T: Temperature> ThermalEnvironment<T> {
+    /// Computes the predicted mean vote, following the Fanger model of ISO 7730.
+    ///
+    /// See [`PredictedMeanVote`].
+    pub fn pmv(&self) -> PredictedMeanVote {
+        const EPSILON: f32 = 0.000_15;
+        const MAX_ITERATIONS: usize = 150;
+
+        let ta = self.air_temperature.celsius().value();
+        let tr = self.mean_radiant_temperature.celsius().value();
+        let vapour_pressure =
+            self.relative_humidity.value() * 10. * (16.6536 - 4_030.183 / (ta + 235.)).exp();
+        let icl = 0.155 * self.clothing_insulation;
+        let m = self.metabolic_rate * 58.15;
+        let fcl = if icl <= 0.078 {
+            1. + 1.29 * icl
+        } else {
+            1.05 + 0.645 * icl
+        };
+        let hcf = 12.1 * self.air_speed.value().sqrt();
+        let taa = ta + 273.;
+        let tra = tr + 273.;
+
+        // Iterative computation of the clothing surface temperature.
+        let tcla = taa + (35.5 - ta) / (3.5 * icl + 0.1);
+        let p1 = icl * fcl;
+        let p2 = p1 * 3.96;
+        let p3 = p1 * 100.;
+        let p4 = p1 * taa;
+        let p5 = 308.7 - 0.028 * m + p2 * (tra / 100.).powi(4);
+        let mut xn = tcla / 100.;
+        let mut xf = tcla / 50.;
+        let mut hc = hcf;
+        for _ in 0..MAX_ITERATIONS {
+            if (xn - xf).abs() <= EPSILON {
+                break;
+            }
+            xf = (xf + xn) / 2.;
+            let hcn = 2.38 * (100. * xf - taa).abs().powf(0.25);
+            hc = hcf.max(hcn);
+            xn = (p5 + p4 * hc - p2 * xf.powi(4)) / (100. + p3 * hc);
+        }
+        let tcl = 100. * xn - 273.;
+
+        // Heat losses.
+        let skin_diffusion = 3.05 * 0.001 * (5_733. - 6.99 * m - vapour_pressure);
+        let sweating = if m > 58.15 { 0.42 * (m - 58.15) } else { 0. };
+        let latent_respiration = 1.7 * 0.000_01 * m * (5_867. - vapour_pressure);
+        let dry_respiration = 0.0014 * m * (34. - ta);
+        let radiation = 3.96 * fcl * (xn.powi(4) - (tra / 100.).powi(4));
+        let convection = fcl * hc * (tcl - ta);
+
+        let ts = 0.303 * (-0.036 * m).exp() + 0.028;
+        PredictedMeanVote(
+            ts * (m
+                - skin_diffusion
+                - sweating
+                - latent_respiration
+                - dry_respiration
+                - radiation
+                - convection),
+        )
+    }
+
+    /// Computes the predicted percentage of dissatisfied (in %).
+    ///
+    /// See [`PredictedMeanVote::ppd()`].
+    pub fn ppd(&self) -> f32 {
+        self.pmv().ppd()
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for ThermalEnvironment<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.air_temperature.eq(&other.air_temperature)
+            && self
+                .mean_radiant_temperature
+                .eq(&other.mean_radiant_temperature)
+            && self.air_speed.eq(&other.air_speed)
+            && self.relative_humidity.eq(&other.relative_humidity)
+            && relative_eq!(self.metabolic_rate, other.metabolic_rate, epsilon = 0.01)
+            && relative_eq!(
+                self.clothing_insulation,
+                other.clothing_insulation,
+                epsilon = 0.01
+            )
+    }
+}
+
+impl From<ThermalEnvironment<Fahrenheit>> for ThermalEnvironment<Celsius> {
+    fn from(value: ThermalEnvironment<Fahrenheit>) -> Self {
+        Self {
+            air_temperature: value.air_temperature.celsius(),
+            mean_radiant_temperature: value.mean_radiant_temperature.celsius(),
+            air_speed: value.air_speed,
+            relative_humidity: value.relative_humidity,
+            metabolic_rate: value.metabolic_rate,
+            clothing_insulation: value.clothing_insulation,
+        }
+    }
+}
+
+impl From<ThermalEnvironment<Celsius>> for ThermalEnvironment<Fahrenheit> {
+    fn from(value: ThermalEnvironment<Celsius>) -> Self {
+        Self {
+            air_temperature: value.air_temperature.fahrenheit(),
+            mean_radiant_temperature: value.mean_radiant_temperature.fahrenheit(),
+            air_speed: value.air_speed,
+            relative_humidity: value.relative_humidity,
+            metabolic_rate: value.metabolic_rate,
+            clothing_insulation: value.clothing_insulation,
+        }
+    }
+}
+
+/// The running mean outdoor temperature.
+///
+/// The running mean outdoor temperature is the exponentially weighted running mean of the daily
+/// mean outdoor temperatures, as defined by EN 16798-1. It is the input of the adaptive thermal
+/// comfort model, used for naturally ventilated buildings.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunningMeanOutdoorTemperature<T: Temperature>(T);
+
+impl<T: Temperature> RunningMeanOutdoorTemperature<T> {
+    /// The weighting factor of the running mean, as recommended by EN 16798-1.
+    const ALPHA: f32 = 0.8;
+
+    /// Computes the running mean outdoor temperature from daily mean outdoor temperatures,
+    /// ordered from the oldest to the most recent one (the one of the previous day).
+    ///
+    /// Returns an error if no daily mean temperature is given.
+    pub fn from_daily_mean_temperatures(
+        daily_mean_temperatures: &[T],
+    ) -> Result<Self, &'static str> {
+        if daily_mean_temperatures.is_empty() {
+            return Err("At least one daily mean temperature is needed");
+        }
+        let (sum, weights, _) = daily_mean_temperatures.iter().rev().fold(
+            (0., 0., 1.),
+            |(sum, weights, weight), temperature| {
+                (
+                    sum + weight * temperature.celsius().value(),
+                    weights + weight,
+                    weight * Self::ALPHA,
+                )
+            },
+        );
+        Ok(Self(T::from_celsius(Celsius(sum / weights))))
+    }
+
+    /// Computes the optimal operative temperature of the EN 16798-1 adaptive model.
+    pub fn comfort_temperature(&self) -> T {
+        T::from_celsius(Celsius(0.33 * self.celsius().value() + 18.8))
+    }
+
+    /// Computes the range of acceptable operative temperatures of the EN 16798-1 adaptive model,
+    /// for the given category.
+    ///
+    /// The upper limit applies to a running mean outdoor temperature between 10°C and 30°C, but
+    /// the lower limit only between 15°C and 30°C: below 15°C, the lower limit is the one at a
+    /// running mean outdoor temperature of 15°C.
+    ///
+    /// Returns an error if the running mean outdoor temperature is outside of the range of
+    /// validity of the model (between 10°C and 30°C).
+    pub fn comfort_band(
+        &self,
+        category: AdaptiveComfortCategory,
+    ) -> Result<ComfortBand<T>, &'static str> {
+        if !(10.0..=30.0).contains(&self.celsius().value()) {
+            return Err("Running mean outdoor temperature must be between 10 and 30 °C");
+        }
+        let (lower, upper) = match category {
+            AdaptiveComfortCategory::I => (3., 2.),
+            AdaptiveComfortCategory::II => (4., 3.),
+            AdaptiveComfortCategory::III => (5., 4.),
+        };
+        let comfort_temperature = self.comfort_temperature().celsius().value();
+        let lower_comfort_temperature = 0.33 * self.celsius().value().max(15.) + 18.8;
+        Ok(ComfortBand {
+            lower: T::from_celsius(Celsius(lower_comfort_temperature - lower)),
+            upper: T::from_celsius(Celsius(comfort_temperature + upper)),
+        })
+    }
+}
+
+impl<T: Temperature> Deref for RunningMeanOutdoorTemperature<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The category of a building for the adaptive thermal comfort model, as defined by EN 16798-1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdaptiveComfortCategory {
+    /// Category I: high level of expectation, for occupants that are very sensitive and fragile.
+    I,
+    /// Category II: normal level of expectation, for new and renovated buildings.
+    II,
+    /// Category III: moderate level of expectation, for existing buildings.
+    III,
+}
+
+/// A range of acceptable operative temperatures.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ComfortBand<T: Temperature> {
+    /// The lower limit of the operative temperature (either in °C or °F).
+    pub lower: T,
+    /// The upper limit of the operative temperature (either in °C or °F).
+    pub upper: T,
+}
+
+impl<T: Temperature> ComfortBand<T> {
+    /// Check whether the given operative temperature is within the comfort band.
+    pub fn contains(&self, operative_temperature: T) -> bool {
+        (self.lower.celsius().value()..=self.upper.celsius().value())
+            .contains(&operative_temperature.celsius().value())
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for ComfortBand<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.lower.eq(&other.lower) && self.upper.eq(&other.upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    // Reference values from ISO 7730:2005, Annex D, Table D.1.
+    #[rstest]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(22.), mean_radiant_temperature: Celsius(22.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }, -0.75, 17.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(27.), mean_radiant_temperature: Celsius(27.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }, 0.77, 17.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(27.), mean_radiant_temperature: Celsius(27.), air_speed: WindSpeed(0.3), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }, 0.44, 9.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(23.5), mean_radiant_temperature: Celsius(25.5), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }, -0.01, 5.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(23.5), mean_radiant_temperature: Celsius(25.5), air_speed: WindSpeed(0.3), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }, -0.55, 11.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(19.), mean_radiant_temperature: Celsius(19.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(40.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 1. }, -0.60, 13.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(23.5), mean_radiant_temperature: Celsius(23.5), air_speed: WindSpeed(0.3), relative_humidity: RelativeHumidity::new(40.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 1. }, 0.12, 5.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(23.), mean_radiant_temperature: Celsius(21.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(40.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 1. }, 0.05, 5.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(23.), mean_radiant_temperature: Celsius(21.), air_speed: WindSpeed(0.3), relative_humidity: RelativeHumidity::new(40.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 1. }, -0.16, 6.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(22.), mean_radiant_temperature: Celsius(22.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.6, clothing_insulation: 0.5 }, 0.05, 5.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(27.), mean_radiant_temperature: Celsius(27.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.6, clothing_insulation: 0.5 }, 1.17, 34.)]
+    #[case(ThermalEnvironment{ air_temperature: Celsius(27.), mean_radiant_temperature: Celsius(27.), air_speed: WindSpeed(0.3), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.6, clothing_insulation: 0.5 }, 0.95, 24.)]
+    #[case(
+        ThermalEnvironment{ air_temperature: Fahrenheit(71.6), mean_radiant_temperature: Fahrenheit(71.6), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.6, clothing_insulation: 0.5 },
+        0.05,
+        5.
+    )]
+    fn test_pmv_ppd_computation<T: Temperature>(
+        #[case] input: ThermalEnvironment<T>,
+        #[case] expected_pmv: f32,
+        #[case] expected_ppd: f32,
+    ) {
+        assert_relative_eq!(input.pmv().value(), expected_pmv, epsilon = 0.01);
+        assert_relative_eq!(input.ppd(), expected_ppd, epsilon = 0.5);
+    }
+
+    #[rstest]
+    #[case(PredictedMeanVote(0.), 5., ThermalEnvironmentCategory::A)]
+    #[case(PredictedMeanVote(-0.15), 5.47, ThermalEnvironmentCategory::A)]
+    #[case(PredictedMeanVote(0.4), 8.33, ThermalEnvironmentCategory::B)]
+    #[case(PredictedMeanVote(-0.6), 12.55, ThermalEnvironmentCategory::C)]
+    #[case(
+        PredictedMeanVote(1.5),
+        50.9,
+        ThermalEnvironmentCategory::OutOfCategories
+    )]
+    fn test_pmv_category(
+        #[case] input: PredictedMeanVote,
+        #[case] expected_ppd: f32,
+        #[case] expected_category: ThermalEnvironmentCategory,
+    ) {
+        assert_relative_eq!(input.ppd(), expected_ppd, epsilon = 0.01);
+        assert_eq!(input.category(), expected_category);
+    }
+
+    #[rstest]
+    #[case(&[Celsius(20.)], Celsius(20.))]
+    #[case(&[Celsius(10.), Celsius(20.)], Celsius(15.56))]
+    #[case(&[Celsius(14.), Celsius(16.), Celsius(15.), Celsius(18.), Celsius(20.), Celsius(19.), Celsius(22.)], Celsius(18.8))]
+    #[case(&[Fahrenheit(50.), Fahrenheit(68.)], Fahrenheit(60.))]
+    fn test_running_mean_outdoor_temperature_computation<T: Temperature>(
+        #[case] input: &[T],
+        #[case] expected_running_mean: T,
+    ) {
+        assert_relative_eq!(
+            RunningMeanOutdoorTemperature::from_daily_mean_temperatures(input)
+                .unwrap()
+                .value(),
+            expected_running_mean.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    fn test_running_mean_outdoor_temperature_without_daily_mean_temperatures() {
+        assert_eq!(
+            RunningMeanOutdoorTemperature::<Celsius>::from_daily_mean_temperatures(&[]).err(),
+            Some("At least one daily mean temperature is needed")
+        );
+    }
+
+    #[rstest]
+    #[case(
+        Celsius(20.),
+        AdaptiveComfortCategory::I,
+        Celsius(25.4),
+        Celsius(22.4),
+        Celsius(27.4)
+    )]
+    #[case(
+        Celsius(20.),
+        AdaptiveComfortCategory::II,
+        Celsius(25.4),
+        Celsius(21.4),
+        Celsius(28.4)
+    )]
+    #[case(
+        Celsius(20.),
+        AdaptiveComfortCategory::III,
+        Celsius(25.4),
+        Celsius(20.4),
+        Celsius(29.4)
+    )]
+    #[case(
+        Celsius(10.),
+        AdaptiveComfortCategory::II,
+        Celsius(22.1),
+        Celsius(19.75),
+        Celsius(25.1)
+    )]
+    #[case(
+        Celsius(12.),
+        AdaptiveComfortCategory::I,
+        Celsius(22.76),
+        Celsius(20.75),
+        Celsius(24.76)
+    )]
+    #[case(
+        Fahrenheit(68.),
+        AdaptiveComfortCategory::I,
+        Fahrenheit(77.72),
+        Fahrenheit(72.32),
+        Fahrenheit(81.32)
+    )]
+    fn test_adaptive_comfort_band_computation<T: Temperature>(
+        #[case] running_mean: T,
+        #[case] category: AdaptiveComfortCategory,
+        #[case] expected_comfort_temperature: T,
+        #[case] expected_lower: T,
+        #[case] expected_upper: T,
+    ) {
+        let running_mean = RunningMeanOutdoorTemperature(running_mean);
+        assert_relative_eq!(
+            running_mean.comfort_temperature().value(),
+            expected_comfort_temperature.value(),
+            epsilon = 0.01
+        );
+        let comfort_band = running_mean.comfort_band(category).unwrap();
+        assert_relative_eq!(
+            comfort_band.lower.value(),
+            expected_lower.value(),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            comfort_band.upper.value(),
+            expected_upper.value(),
+            epsilon = 0.01
+        );
+    }
+
+    #[rstest]
+    #[case(Celsius(5.), AdaptiveComfortCategory::I)]
+    #[case(Celsius(31.), AdaptiveComfortCategory::III)]
+    #[case(Fahrenheit(95.), AdaptiveComfortCategory::II)]
+    fn test_adaptive_comfort_band_out_of_range<T: Temperature>(
+        #[case] running_mean: T,
+        #[case] category: AdaptiveComfortCategory,
+    ) {
+        assert_eq!(
+            RunningMeanOutdoorTemperature(running_mean)
+                .comfort_band(category)
+                .err(),
+            Some("Running mean outdoor temperature must be between 10 and 30 °C")
+        );
+    }
+
+    #[rstest]
+    #[case(ComfortBand{ lower: Celsius(21.4), upper: Celsius(28.4) }, Celsius(25.), true)]
+    #[case(ComfortBand{ lower: Celsius(21.4), upper: Celsius(28.4) }, Celsius(21.4), true)]
+    #[case(ComfortBand{ lower: Celsius(21.4), upper: Celsius(28.4) }, Celsius(20.), false)]
+    #[case(ComfortBand{ lower: Fahrenheit(70.52), upper: Fahrenheit(83.12) }, Fahrenheit(85.), false)]
+    fn test_comfort_band_contains<T: Temperature>(
+        #[case] comfort_band: ComfortBand<T>,
+        #[case] operative_temperature: T,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(comfort_band.contains(operative_temperature), expected);
+    }
+
+    #[rstest]
+    #[case(
+        ThermalEnvironment{ air_temperature: Celsius(22.), mean_radiant_temperature: Celsius(25.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 },
+        ThermalEnvironment{ air_temperature: Fahrenheit(71.6), mean_radiant_temperature: Fahrenheit(77.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }
+    )]
+    fn test_thermal_environment_celsius_to_fahrenheit_conversion(
+        #[case] input: ThermalEnvironment<Celsius>,
+        #[case] expected: ThermalEnvironment<Fahrenheit>,
+    ) {
+        let value: ThermalEnvironment<Fahrenheit> = input.into();
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(
+        ThermalEnvironment{ air_temperature: Fahrenheit(71.6), mean_radiant_temperature: Fahrenheit(77.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 },
+        ThermalEnvironment{ air_temperature: Celsius(22.), mean_radiant_temperature: Celsius(25.), air_speed: WindSpeed(0.1), relative_humidity: RelativeHumidity::new(60.).unwrap(), metabolic_rate: 1.2, clothing_insulation: 0.5 }
+    )]
+    fn test_thermal_environment_fahrenheit_to_celsius_conversion(
+        #[case] input: ThermalEnvironment<Fahrenheit>,
+        #[case] expected: ThermalEnvironment<Celsius>,
+    ) {
+        let value: ThermalEnvironment<Celsius> = input.into();
+        assert_eq!(value, expected);
+    }
+}
@@ -0,0 +1,287 @@
+use core::ops::Deref;
+
+#[allow(unused_imports)]
+#[cfg(feature = "no-std")]
+use micromath::F32Ext;
+#[cfg(not(feature = "no-std"))]
+extern crate std;
+
+use approx::relative_eq;
+
+use crate::{Celsius, Fahrenheit, Temperature};
+
+const KILOMETERS_PER_HOUR: f32 = 1. / 3.6;
+const MILES_PER_HOUR: f32 = 0.447_04;
+const KNOTS: f32 = 1_852. / 3_600.;
+
+/// The wind speed type (in m/s).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WindSpeed(pub f32);
+
+impl WindSpeed {
+    /// Create a WindSpeed from a value in kilometers per hour (km/h).
+    pub fn from_kilometers_per_hour(value: f32) -> Self {
+        Self(value * KILOMETERS_PER_HOUR)
+    }
+
+    /// Create a WindSpeed from a value in miles per hour (mph).
+    pub fn from_miles_per_hour(value: f32) -> Self {
+        Self(value * MILES_PER_HOUR)
+    }
+
+    /// Create a WindSpeed from a value in knots (kn).
+    pub fn from_knots(value: f32) -> Self {
+        Self(value * KNOTS)
+    }
+
+    /// Get the value of the wind speed (in m/s).
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+
+    /// Get the wind speed in meters per second (m/s).
+    pub fn meters_per_second(&self) -> f32 {
+        self.0
+    }
+
+    /// Get the wind speed in kilometers per hour (km/h).
+    pub fn kilometers_per_hour(&self) -> f32 {
+        self.0 / KILOMETERS_PER_HOUR
+    }
+
+    /// Get the wind speed in miles per hour (mph).
+    pub fn miles_per_hour(&self) -> f32 {
+        self.0 / MILES_PER_HOUR
+    }
+
+    /// Get the wind speed in knots (kn).
+    pub fn knots(&self) -> f32 {
+        self.0 / KNOTS
+    }
+}
+
+impl From<f32> for WindSpeed {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq for WindSpeed {
+    fn eq(&self, other: &Self) -> bool {
+        relative_eq!(self.0, other.0, epsilon = 0.01)
+    }
+}
+
+/// The wind chill.
+///
+/// The wind chill indicates how cold the human body feels the air when the wind blows, as
+/// the wind increases the heat loss of exposed skin.
+/// The wind chill uses the JAG/TI formula adopted in 2001 by the US National Weather Service
+/// and Environment Canada. It is only defined for a temperature at or below 10°C (50°F) and a
+/// wind speed of at least 4.8 km/h (3 mph); outside of this range the wind chill is equal to
+/// the air temperature.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WindChill<T: Temperature>(T);
+
+impl<T: Temperature> WindChill<T> {
+    /// Get the frostbite risk corresponding to the wind chill.
+    pub fn frostbite_risk(&self) -> FrostbiteRisk {
+        if self.celsius().value() > -10. {
+            FrostbiteRisk::Low
+        } else if self.celsius().value() > -28. {
+            FrostbiteRisk::Moderate
+        } else if self.celsius().value() > -40. {
+            FrostbiteRisk::High
+        } else if self.celsius().value() > -48. {
+            FrostbiteRisk::VeryHigh
+        } else if self.celsius().value() > -55. {
+            FrostbiteRisk::Severe
+        } else {
+            FrostbiteRisk::Extreme
+        }
+    }
+}
+
+impl<T: Temperature> Deref for WindChill<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The frostbite risk corresponding to a wind chill, following the Environment Canada
+/// categories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrostbiteRisk {
+    /// Low risk: wind chill above -10°C.
+    Low,
+    /// Moderate risk: wind chill between -10°C and -28°C, risk of hypothermia when outside
+    /// for long periods.
+    Moderate,
+    /// High risk: wind chill between -28°C and -40°C, exposed skin can freeze in 10 to 30
+    /// minutes.
+    High,
+    /// Very high risk: wind chill between -40°C and -48°C, exposed skin can freeze in 5 to 10
+    /// minutes.
+    VeryHigh,
+    /// Severe risk: wind chill between -48°C and -55°C, exposed skin can freeze in 2 to 5
+    /// minutes.
+    Severe,
+    /// Extreme risk: wind chill below -55°C, exposed skin can freeze in less than 2 minutes.
+    Extreme,
+}
+
+/// The combination of the temperature and the wind speed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TemperatureAndWindSpeed<T: Temperature> {
+    /// The temperature (either in °C or °F).
+    pub temperature: T,
+    /// The wind speed at 10 m above the ground (in m/s).
+    pub wind_speed: WindSpeed,
+}
+
+impl<T: Temperature> TemperatureAndWindSpeed<T> {
+    /// Computes the wind chill.
+    ///
+    /// See [`WindChill`].
+    pub fn wind_chill(&self) -> WindChill<T> {
+        let temperature = self.temperature.celsius().value();
+        let wind_speed = self.wind_speed.kilometers_per_hour();
+        if temperature > 10. || wind_speed < 4.8 {
+            return WindChill(self.temperature);
+        }
+        let v = wind_speed.powf(0.16);
+        WindChill(T::from_celsius(Celsius(
+            13.12 + 0.6215 * temperature - 11.37 * v + 0.3965 * temperature * v,
+        )))
+    }
+}
+
+impl<T: Temperature + PartialEq> PartialEq for TemperatureAndWindSpeed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.wind_speed.eq(&other.wind_speed) && self.temperature.eq(&other.temperature)
+    }
+}
+
+impl From<TemperatureAndWindSpeed<Fahrenheit>> for TemperatureAndWindSpeed<Celsius> {
+    fn from(value: TemperatureAndWindSpeed<Fahrenheit>) -> Self {
+        Self {
+            temperature: value.temperature.celsius(),
+            wind_speed: value.wind_speed,
+        }
+    }
+}
+
+impl From<TemperatureAndWindSpeed<Celsius>> for TemperatureAndWindSpeed<Fahrenheit> {
+    fn from(value: TemperatureAndWindSpeed<Celsius>) -> Self {
+        Self {
+            temperature: value.temperature.fahrenheit(),
+            wind_speed: value.wind_speed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(WindSpeed(10.), 10., 36., 22.37, 19.44)]
+    #[case(WindSpeed::from_kilometers_per_hour(50.), 13.89, 50., 31.07, 27.0)]
+    #[case(WindSpeed::from_miles_per_hour(15.), 6.71, 24.14, 15., 13.03)]
+    #[case(WindSpeed::from_knots(20.), 10.29, 37.04, 23.02, 20.)]
+    #[case(0.0.into(), 0., 0., 0., 0.)]
+    fn test_wind_speed_conversion(
+        #[case] input: WindSpeed,
+        #[case] expected_meters_per_second: f32,
+        #[case] expected_kilometers_per_hour: f32,
+        #[case] expected_miles_per_hour: f32,
+        #[case] expected_knots: f32,
+    ) {
+        assert_relative_eq!(input.value(), expected_meters_per_second, epsilon = 0.01);
+        assert_relative_eq!(
+            input.meters_per_second(),
+            expected_meters_per_second,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input.kilometers_per_hour(),
+            expected_kilometers_per_hour,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            input.miles_per_hour(),
+            expected_miles_per_hour,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(input.knots(), expected_knots, epsilon = 0.01);
+    }
+
+    #[rstest]
+    #[case(WindSpeed(3.2), WindSpeed(3.201))]
+    #[case(WindSpeed::from_knots(10.), WindSpeed(5.144))]
+    fn test_wind_speed_eq(#[case] a: WindSpeed, #[case] b: WindSpeed) {
+        assert_eq!(a, b);
+    }
+
+    #[rstest]
+    #[case(WindSpeed(0.0), WindSpeed(0.1))]
+    #[case(WindSpeed::from_kilometers_per_hour(10.), WindSpeed(10.))]
+    fn test_wind_speed_ne(#[case] a: WindSpeed, #[case] b: WindSpeed) {
+        assert_ne!(a, b);
+    }
+
+    #[rstest]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(5.), wind_speed: WindSpeed::from_kilometers_per_hour(10.) }, Celsius(2.66), FrostbiteRisk::Low)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(-10.), wind_speed: WindSpeed::from_kilometers_per_hour(20.) }, Celsius(-17.86), FrostbiteRisk::Moderate)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(-20.), wind_speed: WindSpeed::from_kilometers_per_hour(30.) }, Celsius(-32.57), FrostbiteRisk::High)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(-30.), wind_speed: WindSpeed::from_kilometers_per_hour(40.) }, Celsius(-47.5), FrostbiteRisk::VeryHigh)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(-32.), wind_speed: WindSpeed::from_kilometers_per_hour(50.) }, Celsius(-51.76), FrostbiteRisk::Severe)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(-40.), wind_speed: WindSpeed::from_kilometers_per_hour(60.) }, Celsius(-64.17), FrostbiteRisk::Extreme)]
+    #[case(TemperatureAndWindSpeed{ temperature: Fahrenheit(0.), wind_speed: WindSpeed::from_miles_per_hour(15.) }, Fahrenheit(-19.45), FrostbiteRisk::High)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(15.), wind_speed: WindSpeed::from_kilometers_per_hour(30.) }, Celsius(15.), FrostbiteRisk::Low)]
+    #[case(TemperatureAndWindSpeed{ temperature: Celsius(-5.), wind_speed: WindSpeed::from_kilometers_per_hour(3.) }, Celsius(-5.), FrostbiteRisk::Low)]
+    fn test_wind_chill_computation<T: Temperature>(
+        #[case] input: TemperatureAndWindSpeed<T>,
+        #[case] expected_wind_chill: T,
+        #[case] expected_frostbite_risk: FrostbiteRisk,
+    ) {
+        let wind_chill = input.wind_chill();
+        assert_relative_eq!(
+            wind_chill.value(),
+            expected_wind_chill.value(),
+            epsilon = 0.01
+        );
+        assert_eq!(wind_chill.frostbite_risk(), expected_frostbite_risk);
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureAndWindSpeed{ temperature: Celsius(-7.49), wind_speed: WindSpeed(4.2) },
+        TemperatureAndWindSpeed{ temperature: Fahrenheit(18.52), wind_speed: WindSpeed(4.2) }
+    )]
+    fn test_temperature_and_wind_speed_celsius_to_fahrenheit_conversion(
+        #[case] input: TemperatureAndWindSpeed<Celsius>,
+        #[case] expected: TemperatureAndWindSpeed<Fahrenheit>,
+    ) {
+        let value: TemperatureAndWindSpeed<Fahrenheit> = input.into();
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(
+        TemperatureAndWindSpeed{ temperature: Fahrenheit(18.52), wind_speed: WindSpeed(4.2) },
+        TemperatureAndWindSpeed{ temperature: Celsius(-7.49), wind_speed: WindSpeed(4.2) }
+    )]
+    fn test_temperature_and_wind_speed_fahrenheit_to_celsius_conversion(
+        #[case] input: TemperatureAndWindSpeed<Fahrenheit>,
+        #[case] expected: TemperatureAndWindSpeed<Celsius>,
+    ) {
+        let value: TemperatureAndWindSpeed<Celsius> = input.into();
+        assert_eq!(value, expected);
+    }
+}